@@ -0,0 +1,46 @@
+use advent_2022::input::{Loader, Source};
+use advent_2022::registry;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::path::Path;
+
+/// Benchmarks each registered day's generator and parts separately, against the real input.
+///
+/// `cargo bench-save` saves a baseline named `main` and `cargo bench-compare` compares a later
+/// change against it (aliases in `.cargo/config.toml`).
+fn days(c: &mut Criterion) {
+    let loader = Loader::from_env(Path::new(env!("CARGO_MANIFEST_DIR")));
+
+    for entry in registry::entries() {
+        let input = match loader.load(&Source::Puzzle, entry.year, entry.day) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("skipping {} {}: {}", entry.year, entry, e);
+                continue;
+            }
+        };
+        let parsed = entry
+            .parse(&input)
+            .unwrap_or_else(|e| panic!("{}: {}", entry, e));
+        let params = entry.default_params();
+
+        let mut group = c.benchmark_group(format!("{}/day{}", entry.year, entry.day));
+
+        group.bench_function("generator", |b| {
+            b.iter(|| entry.parse(black_box(&input)).unwrap())
+        });
+        for part in entry.parts() {
+            group.bench_function(format!("part{}", part), |b| {
+                b.iter(|| {
+                    entry
+                        .solve_with(black_box(&parsed), part, &*params)
+                        .unwrap()
+                })
+            });
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,109 @@
+use crate::grid::Grid;
+use std::fmt;
+
+/// What a part of a puzzle produces: a number, some text, or a picture to be read off a
+/// screen like day 10's CRT.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    Bitmap(Grid<bool>),
+}
+
+impl Answer {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Int(_) => "int",
+            Answer::Text(_) => "text",
+            Answer::Bitmap(_) => "bitmap",
+        }
+    }
+
+    /// Serialises the answer as a JSON object tagged with its `kind`.
+    pub fn to_json(&self) -> String {
+        match self {
+            Answer::Int(n) => format!(r#"{{"type":"int","value":{}}}"#, n),
+            Answer::Text(s) => format!(r#"{{"type":"text","value":{}}}"#, json_string(s)),
+            Answer::Bitmap(pixels) => {
+                let rows = render_bitmap(pixels)
+                    .lines()
+                    .map(json_string)
+                    .collect::<Vec<_>>();
+
+                format!(
+                    r#"{{"type":"bitmap","width":{},"height":{},"rows":[{}]}}"#,
+                    pixels.width(),
+                    pixels.height(),
+                    rows.join(",")
+                )
+            }
+        }
+    }
+}
+
+fn render_bitmap(pixels: &Grid<bool>) -> String {
+    pixels.render(|&lit| if lit { '#' } else { '.' })
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Bitmap(pixels) => write!(f, "{}", render_bitmap(pixels)),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Int(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl From<Grid<bool>> for Answer {
+    fn from(pixels: Grid<bool>) -> Self {
+        Answer::Bitmap(pixels)
+    }
+}
+
+/// Quotes `s` as a JSON string.
+pub(crate) fn json_string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+
+    quoted
+}
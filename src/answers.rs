@@ -0,0 +1,95 @@
+use crate::registry;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// A checked-in answer for one part, run against `file` under the year's input directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnownAnswer {
+    pub day: u8,
+    pub part: u8,
+    pub file: String,
+    pub answer: String,
+}
+
+/// Parses an answers file: one `<day> <part> <file> <answer>` per line, with blank lines and
+/// `#` comments ignored. Multi-line answers such as bitmaps write their line breaks as `\n`.
+pub fn parse(text: &str) -> io::Result<Vec<KnownAnswer>> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| {
+            let malformed = || {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("line {}: malformed known answer {:?}", i + 1, line),
+                )
+            };
+
+            let fields = line.splitn(4, ' ').collect::<Vec<_>>();
+            match fields[..] {
+                [day, part, file, answer] => Ok(KnownAnswer {
+                    day: day.parse().map_err(|_| malformed())?,
+                    part: part.parse().map_err(|_| malformed())?,
+                    file: file.to_string(),
+                    answer: answer.replace("\\n", "\n"),
+                }),
+                _ => Err(malformed()),
+            }
+        })
+        .collect()
+}
+
+pub fn load(path: &Path) -> io::Result<Vec<KnownAnswer>> {
+    parse(&fs::read_to_string(path)?)
+}
+
+#[derive(Debug)]
+pub enum Mismatch {
+    Unregistered,
+    Unreadable(io::Error),
+    Failed(String),
+    Wrong(String),
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mismatch::Unregistered => write!(f, "no registered solution"),
+            Mismatch::Unreadable(e) => write!(f, "could not read input: {}", e),
+            Mismatch::Failed(e) => write!(f, "failed: {}", e),
+            Mismatch::Wrong(answer) => write!(f, "got {}", answer),
+        }
+    }
+}
+
+/// Runs every known answer for `year` against its input in `input_dir`, returning the ones
+/// that don't match alongside what went wrong.
+pub fn verify<'a>(
+    year: u16,
+    answers: &'a [KnownAnswer],
+    input_dir: &Path,
+) -> Vec<(&'a KnownAnswer, Mismatch)> {
+    answers
+        .iter()
+        .filter_map(|known| {
+            let entry = registry::find(year, known.day)
+                .filter(|entry| entry.parts().any(|part| part == known.part));
+
+            let mismatch = match entry {
+                None => Mismatch::Unregistered,
+                Some(entry) => match fs::read_to_string(input_dir.join(&known.file)) {
+                    Err(e) => Mismatch::Unreadable(e),
+                    Ok(input) => match entry.run(&input, known.part) {
+                        Ok(run) if run.answer.to_string() == known.answer => return None,
+                        Ok(run) => Mismatch::Wrong(run.answer.to_string()),
+                        Err(e) => Mismatch::Failed(e.to_string()),
+                    },
+                },
+            };
+
+            Some((known, mismatch))
+        })
+        .collect()
+}
@@ -0,0 +1,229 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    InvalidInt(ParseIntError),
+    MissingDelimiter(&'static str),
+    Unexpected(&'static str),
+    UnexpectedEnd(&'static str),
+}
+use ParseErrorKind::*;
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidInt(e) => write!(f, "invalid integer ({})", e),
+            MissingDelimiter(delim) => write!(f, "missing delimiter {:?}", delim),
+            Unexpected(expected) => write!(f, "expected {}", expected),
+            UnexpectedEnd(expected) => write!(f, "input ended, expected {}", expected),
+        }
+    }
+}
+
+/// A parse failure, located by day, 1-based line and column, and the text that caused it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub kind: ParseErrorKind,
+}
+
+impl ParseError {
+    /// Builds an error for `token`, which must be a subslice of `source`; the line and
+    /// column are derived from where `token` sits inside `source`.
+    pub fn at(day: u8, source: &str, token: &str, kind: ParseErrorKind) -> Self {
+        let (line, column) = position_in(source, token);
+
+        ParseError {
+            day,
+            line,
+            column,
+            text: token.to_string(),
+            kind,
+        }
+    }
+
+    /// Re-locates an error raised while parsing `token` so it is relative to `source`.
+    pub fn inside(mut self, source: &str, token: &str) -> Self {
+        let (line, column) = position_in(source, token);
+
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+        self
+    }
+
+    /// Moves the error down by `lines`, for when `source` started partway through the input.
+    pub fn below(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {} at {:?}",
+            self.day, self.line, self.column, self.kind, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+fn position_in(source: &str, token: &str) -> (usize, usize) {
+    let start = source.as_ptr() as usize;
+    let pos = token.as_ptr() as usize;
+
+    let offset = if pos >= start && pos + token.len() <= start + source.len() {
+        pos - start
+    } else {
+        0
+    };
+
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+
+    (line, column)
+}
+
+/// Parses `token` (a subslice of `source`) as an integer.
+pub(crate) fn parse_int<T>(day: u8, source: &str, token: &str) -> Result<T, ParseError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    token
+        .parse()
+        .map_err(|e| ParseError::at(day, source, token, InvalidInt(e)))
+}
+
+/// Parses every line of `input` with `T::from_str`, fixing up the line number of any error.
+pub(crate) fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| line.parse().map_err(|e: ParseError| e.below(i)))
+        .collect()
+}
+
+/// A parsed input that has no answer, such as a signal with no start-of-packet marker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub day: u8,
+    pub reason: &'static str,
+}
+
+impl SolveError {
+    pub fn new(day: u8, reason: &'static str) -> Self {
+        SolveError { day, reason }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {}: {}", self.day, self.reason)
+    }
+}
+
+impl Error for SolveError {}
+
+/// Anything that can go wrong between reading an input and producing an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolutionError {
+    Parse(ParseError),
+    Solve(SolveError),
+    /// A part other than 1 or 2 was asked of `day`.
+    NoSuchPart {
+        day: u8,
+        part: u8,
+    },
+}
+
+impl fmt::Display for SolutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolutionError::Parse(e) => e.fmt(f),
+            SolutionError::Solve(e) => e.fmt(f),
+            SolutionError::NoSuchPart { day, part } => {
+                write!(f, "day {}: no such part: {}", day, part)
+            }
+        }
+    }
+}
+
+impl Error for SolutionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SolutionError::Parse(e) => Some(e),
+            SolutionError::Solve(e) => Some(e),
+            SolutionError::NoSuchPart { .. } => None,
+        }
+    }
+}
+
+impl From<ParseError> for SolutionError {
+    fn from(e: ParseError) -> Self {
+        SolutionError::Parse(e)
+    }
+}
+
+impl From<SolveError> for SolutionError {
+    fn from(e: SolveError) -> Self {
+        SolutionError::Solve(e)
+    }
+}
+
+/// A failure while solving straight from a reader: the reader failed, or what it read was bad.
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Solution(SolutionError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "could not read input: {}", e),
+            StreamError::Solution(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for StreamError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StreamError::Io(e) => Some(e),
+            StreamError::Solution(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> Self {
+        StreamError::Io(e)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(e: ParseError) -> Self {
+        StreamError::Solution(e.into())
+    }
+}
+
+impl From<SolveError> for StreamError {
+    fn from(e: SolveError) -> Self {
+        StreamError::Solution(e.into())
+    }
+}
@@ -0,0 +1,67 @@
+//! Seeded generators of valid puzzle inputs, for stress testing the solutions on inputs far
+//! larger than the real ones.
+//!
+//! The same year, day, seed and size always give the same input.
+
+mod y2022;
+
+/// A small, fast, seedable random number generator (SplitMix64). Not for cryptography.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `low..=high`.
+    pub fn range(&mut self, low: u64, high: u64) -> u64 {
+        assert!(low <= high, "empty range {}..={}", low, high);
+
+        match (high - low).checked_add(1) {
+            Some(span) => low + self.next_u64() % span,
+            None => self.next_u64(),
+        }
+    }
+
+    /// An index below `len`.
+    pub fn below(&mut self, len: usize) -> usize {
+        self.range(0, len as u64 - 1) as usize
+    }
+
+    /// True with probability `numerator / denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.range(1, denominator) <= numerator
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Generates an input for `day` of `year` from `seed`. `size` scales it: roughly how many
+/// lines, elves, trees across or monkeys it has, depending on the day. `None` if the day
+/// has no generator.
+pub fn generate(year: u16, day: u8, seed: u64, size: usize) -> Option<String> {
+    let mut rng = Rng::new(seed);
+
+    match year {
+        2022 => y2022::generate(day, &mut rng, size),
+        _ => None,
+    }
+}
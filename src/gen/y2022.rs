@@ -0,0 +1,332 @@
+use super::Rng;
+use crate::solution::Solution;
+use crate::y2022::day11::{Day11, Day11Params, MonkeyGroup};
+use std::fmt::Write;
+
+pub(super) fn generate(day: u8, rng: &mut Rng, size: usize) -> Option<String> {
+    let size = size.max(1);
+
+    let input = match day {
+        1 => calories(rng, size),
+        2 => strategy_guide(rng, size),
+        3 => rucksacks(rng, size),
+        4 => range_pairs(rng, size),
+        5 => crane_plan(rng, size),
+        6 => datastream(rng, size),
+        7 => terminal_transcript(rng, size),
+        8 => tree_grid(rng, size),
+        9 => rope_moves(rng, size),
+        10 => cpu_program(rng, size),
+        11 => monkey_notes(rng, size),
+        _ => return None,
+    };
+
+    Some(input)
+}
+
+fn lines(lines: impl IntoIterator<Item = String>) -> String {
+    let mut text = lines.into_iter().collect::<Vec<_>>().join("\n");
+    text.push('\n');
+    text
+}
+
+/// `size` elves carrying up to 15 snacks each.
+fn calories(rng: &mut Rng, size: usize) -> String {
+    let elves = (0..size).map(|_| {
+        let items = rng.range(1, 15);
+        (0..items)
+            .map(|_| rng.range(1000, 60000).to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    });
+
+    lines([elves.collect::<Vec<_>>().join("\n\n")])
+}
+
+/// `size` rounds.
+fn strategy_guide(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        format!(
+            "{} {}",
+            rng.pick(&['A', 'B', 'C']),
+            rng.pick(&['X', 'Y', 'Z'])
+        )
+    }))
+}
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// `size` groups of three elves. Each rucksack has exactly one item in both compartments, and
+/// each group has exactly one badge carried by all three.
+fn rucksacks(rng: &mut Rng, size: usize) -> String {
+    let mut rucksacks = Vec::new();
+
+    for _ in 0..size {
+        let mut items = ITEMS.chars().collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+
+        // The badge, then a separate pool of 17 items for each elf, so nothing else is common
+        // to the whole group.
+        let badge = items[0];
+        for pool in items[1..].chunks(17) {
+            let (shared, rest) = (pool[0], &pool[1..]);
+            let (left_pool, right_pool) = rest.split_at(rest.len() / 2);
+            let len = rng.range(4, 16) as usize;
+
+            let mut left = vec![shared, badge];
+            left.extend((2..len).map(|_| *rng.pick(left_pool)));
+            let mut right = vec![shared];
+            right.extend((1..len).map(|_| *rng.pick(right_pool)));
+
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+            rucksacks.push(left.into_iter().chain(right).collect::<String>());
+        }
+    }
+
+    lines(rucksacks)
+}
+
+fn section_range(rng: &mut Rng) -> String {
+    let (a, b) = (rng.range(1, 99), rng.range(1, 99));
+    format!("{}-{}", a.min(b), a.max(b))
+}
+
+/// `size` pairs of section ranges.
+fn range_pairs(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        let l = section_range(rng);
+        format!("{},{}", l, section_range(rng))
+    }))
+}
+
+/// A drawing of up to nine stacks and `size` moves, none of which empties a stack.
+fn crane_plan(rng: &mut Rng, size: usize) -> String {
+    let num_stacks = rng.range(3, 9) as usize;
+    let mut heights = (0..num_stacks)
+        .map(|_| rng.range(2, 8) as usize)
+        .collect::<Vec<_>>();
+    let tallest = *heights.iter().max().unwrap();
+
+    let mut text = String::new();
+    for level in (0..tallest).rev() {
+        let row = heights
+            .iter()
+            .map(|&height| {
+                if level < height {
+                    format!("[{}]", (b'A' + rng.below(26) as u8) as char)
+                } else {
+                    "   ".to_string()
+                }
+            })
+            .collect::<Vec<_>>();
+        writeln!(text, "{}", row.join(" ")).unwrap();
+    }
+    let numbers = (1..=num_stacks)
+        .map(|n| format!(" {:<3}", n))
+        .collect::<String>();
+    writeln!(text, "{}\n", numbers.trim_end()).unwrap();
+
+    let moves = (0..size).map(|_| {
+        let from = loop {
+            let from = rng.below(num_stacks);
+            if heights[from] > 1 {
+                break from;
+            }
+        };
+        let to = (from + 1 + rng.below(num_stacks - 1)) % num_stacks;
+        let count = rng.range(1, heights[from] as u64 - 1) as usize;
+
+        heights[from] -= count;
+        heights[to] += count;
+        format!("move {} from {} to {}", count, from + 1, to + 1)
+    });
+
+    text + &lines(moves)
+}
+
+/// `size` characters of noise, ending with fourteen different characters so both markers
+/// exist.
+fn datastream(rng: &mut Rng, size: usize) -> String {
+    let letters = ITEMS[..26].chars().collect::<Vec<_>>();
+    let mut signal = (0..size).map(|_| *rng.pick(&letters)).collect::<String>();
+
+    let mut marker = letters;
+    rng.shuffle(&mut marker);
+    signal.extend(&marker[..14]);
+
+    lines([signal])
+}
+
+struct Directory {
+    name: String,
+    files: Vec<(String, u64)>,
+    children: Vec<Directory>,
+}
+
+fn random_name(rng: &mut Rng) -> String {
+    let len = rng.range(1, 8);
+    (0..len)
+        .map(|_| (b'a' + rng.below(26) as u8) as char)
+        .collect()
+}
+
+/// A filesystem of about `size` directories, whose total size is between the 40000000 and
+/// 70000000 the second part needs, explored depth first.
+fn terminal_transcript(rng: &mut Rng, size: usize) -> String {
+    let mut root = Directory {
+        name: "/".to_string(),
+        files: Vec::new(),
+        children: Vec::new(),
+    };
+
+    // Hang each new directory somewhere random in the tree grown so far.
+    let mut paths: Vec<Vec<usize>> = vec![vec![]];
+    for i in 0..size {
+        let parent_path = rng.pick(&paths).clone();
+        let mut parent = &mut root;
+        for &child in &parent_path {
+            parent = &mut parent.children[child];
+        }
+
+        parent.children.push(Directory {
+            name: format!("{}_{}", random_name(rng), i),
+            files: Vec::new(),
+            children: Vec::new(),
+        });
+
+        let mut path = parent_path;
+        path.push(parent.children.len() - 1);
+        paths.push(path);
+    }
+
+    fn add_files(dir: &mut Directory, rng: &mut Rng, total: &mut u64) {
+        for i in 0..rng.range(1, 4) {
+            let size = rng.range(1, 1000000);
+            *total += size;
+            dir.files
+                .push((format!("{}{}.dat", random_name(rng), i), size));
+        }
+        for child in &mut dir.children {
+            add_files(child, rng, total);
+        }
+    }
+    let mut total = 0;
+    add_files(&mut root, rng, &mut total);
+
+    // Scale the files so the disk ends up about 55000000 full.
+    fn rescale(dir: &mut Directory, total: u64) {
+        for (_, size) in &mut dir.files {
+            *size = (*size * 55000000 / total).max(1);
+        }
+        for child in &mut dir.children {
+            rescale(child, total);
+        }
+    }
+    rescale(&mut root, total);
+
+    fn explore(dir: &Directory, transcript: &mut Vec<String>) {
+        transcript.push("$ ls".to_string());
+        for child in &dir.children {
+            transcript.push(format!("dir {}", child.name));
+        }
+        for (name, size) in &dir.files {
+            transcript.push(format!("{} {}", size, name));
+        }
+        for child in &dir.children {
+            transcript.push(format!("$ cd {}", child.name));
+            explore(child, transcript);
+            transcript.push("$ cd ..".to_string());
+        }
+    }
+    let mut transcript = vec!["$ cd /".to_string()];
+    explore(&root, &mut transcript);
+
+    lines(transcript)
+}
+
+/// A `size` by `size` forest.
+fn tree_grid(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        (0..size)
+            .map(|_| char::from_digit(rng.range(0, 9) as u32, 10).unwrap())
+            .collect()
+    }))
+}
+
+/// `size` head moves.
+fn rope_moves(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| format!("{} {}", rng.pick(&['U', 'D', 'L', 'R']), rng.range(1, 20))))
+}
+
+/// At least `size` instructions, and enough to draw the whole CRT, keeping the sprite on the
+/// screen.
+fn cpu_program(rng: &mut Rng, size: usize) -> String {
+    let mut x: i64 = 1;
+    let mut cycles = 0;
+    let mut program = Vec::new();
+
+    while program.len() < size || cycles < 240 {
+        if rng.chance(1, 3) {
+            program.push("noop".to_string());
+            cycles += 1;
+        } else {
+            let target = rng.range(0, 39) as i64;
+            program.push(format!("addx {}", target - x));
+            x = target;
+            cycles += 2;
+        }
+    }
+
+    lines(program)
+}
+
+const PRIMES: [u64; 15] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+
+/// Notes on `size` monkeys, at most 15 so worry levels modulo the divisors' product still fit
+/// once squared. Part 1 keeps worry levels exactly, so notes are drawn again until its rounds
+/// play out without overflowing.
+fn monkey_notes(rng: &mut Rng, size: usize) -> String {
+    loop {
+        let notes = some_monkey_notes(rng, size);
+        let fits = Day11::parse(&notes).is_ok_and(|monkeys| {
+            let mut group = MonkeyGroup::new(monkeys);
+            (0..Day11Params::default().relief_rounds).all(|_| group.play_round(true).is_ok())
+        });
+
+        if fits {
+            return notes;
+        }
+    }
+}
+
+fn some_monkey_notes(rng: &mut Rng, size: usize) -> String {
+    let num_monkeys = size.clamp(2, PRIMES.len());
+    let mut divisors = PRIMES.to_vec();
+    rng.shuffle(&mut divisors);
+
+    let notes = (0..num_monkeys).map(|i| {
+        let items = (0..rng.range(1, 8))
+            .map(|_| rng.range(50, 99).to_string())
+            .collect::<Vec<_>>();
+        let operation = match rng.range(0, 4) {
+            0 => "old * old".to_string(),
+            1 | 2 => format!("old + {}", rng.range(1, 9)),
+            _ => format!("old * {}", rng.range(2, 19)),
+        };
+        let mut other = || (i + 1 + rng.below(num_monkeys - 1)) % num_monkeys;
+        let (if_true, if_false) = (other(), other());
+
+        format!(
+            "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}",
+            i,
+            items.join(", "),
+            operation,
+            divisors[i],
+            if_true,
+            if_false
+        )
+    });
+
+    lines([notes.collect::<Vec<_>>().join("\n\n")])
+}
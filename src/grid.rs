@@ -0,0 +1,261 @@
+use crate::error::{ParseError, ParseErrorKind};
+use crate::geom::{Dir4, Dir8, Point2};
+use std::fmt;
+use std::iter::{Skip, StepBy};
+use std::ops::{Index, IndexMut};
+use std::slice::{ChunksExact, Iter};
+
+/// A rectangular grid stored row-major in a single `Vec`, indexed by `(x, y)` with the
+/// origin in the top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid from nested rows, or `None` if the rows are not all the same width.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, |row| row.len());
+        let height = rows.len();
+
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a character map, one row per line, turning each character into a cell with
+    /// `cell`; characters it rejects are reported as not being `expected`.
+    pub fn parse(
+        day: u8,
+        s: &str,
+        expected: &'static str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(s.len());
+
+        for line in s.lines() {
+            for (i, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    ParseError::at(
+                        day,
+                        s,
+                        &line[i..i + c.len_utf8()],
+                        ParseErrorKind::Unexpected(expected),
+                    )
+                })?;
+                cells.push(value);
+            }
+
+            let row_width = line.chars().count();
+            match width {
+                None if row_width == 0 => break,
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::at(
+                        day,
+                        s,
+                        line,
+                        ParseErrorKind::Unexpected("rows of equal width"),
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+
+        let width = width.ok_or_else(|| {
+            ParseError::at(day, s, s, ParseErrorKind::UnexpectedEnd("a row of cells"))
+        })?;
+
+        Ok(Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    /// Every `(x, y)` in the grid, in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> ChunksExact<'_, T> {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> StepBy<Skip<Iter<'_, T>>> {
+        assert!(x < self.width, "column {} out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Walks from `(x, y)` in `dir` until the edge of the grid, yielding each position and
+    /// cell passed; the starting cell is not included.
+    pub fn ray(&self, x: usize, y: usize, dir: impl Into<Dir8>) -> Ray<'_, T> {
+        Ray {
+            grid: self,
+            pos: Point2::new(x as isize, y as isize),
+            step: dir.into().offset(),
+        }
+    }
+
+    /// The in-bounds orthogonal neighbours of `(x, y)`.
+    pub fn neighbours(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(x, y, dir.into()))
+    }
+
+    /// The in-bounds orthogonal and diagonal neighbours of `(x, y)`.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(x, y, dir))
+    }
+
+    fn step(&self, x: usize, y: usize, dir: Dir8) -> Option<(usize, usize)> {
+        let p = Point2::new(x as isize, y as isize).step(dir);
+        self.contains(p).then_some((p.x as usize, p.y as usize))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Renders the grid with one character per cell and one line per row.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&mut f).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get(x, y).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) out of bounds for {}x{} grid",
+                x, y, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) out of bounds for {}x{} grid", x, y, width, height))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    pos: Point2,
+    step: Point2,
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = ((usize, usize), &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pos += self.step;
+
+        if self.grid.contains(self.pos) {
+            let pos = (self.pos.x as usize, self.pos.y as usize);
+            Some((pos, &self.grid[pos]))
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::borrow::Cow;
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Cursor, Read};
+use std::path::{Path, PathBuf};
+
+/// Names a directory to read puzzle inputs from instead of `input/`. It is laid out the same
+/// way, with one subdirectory per year.
+pub const DIR_VAR: &str = "ADVENT_INPUT_DIR";
+
+/// The directory holding `year`'s inputs, under `root`.
+pub fn dir(root: &Path, year: u16) -> PathBuf {
+    root.join("input").join(year.to_string())
+}
+
+/// Where the checked-in answers for `year` live, under `root`.
+pub fn answers_path(root: &Path, year: u16) -> PathBuf {
+    root.join("answers").join(format!("{}.txt", year))
+}
+
+/// Where to read a puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The day's `dayN.txt` in the loader's input directory.
+    Puzzle,
+    File(PathBuf),
+    Stdin,
+    Text(String),
+}
+
+impl Source {
+    /// Reads `-` as stdin and anything else as a path.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path)),
+        }
+    }
+}
+
+/// Finds and reads puzzle inputs.
+#[derive(Debug, Clone)]
+pub struct Loader {
+    dir: PathBuf,
+}
+
+impl Loader {
+    /// A loader reading puzzles from `dir`, which holds one subdirectory per year.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Loader { dir: dir.into() }
+    }
+
+    /// A loader reading puzzles from `$ADVENT_INPUT_DIR` if it is set, or else `input/` under
+    /// `root`.
+    pub fn from_env(root: &Path) -> Self {
+        match env::var_os(DIR_VAR) {
+            Some(dir) => Loader::new(dir),
+            None => Loader::new(root.join("input")),
+        }
+    }
+
+    /// The directory holding `year`'s inputs.
+    pub fn year_dir(&self, year: u16) -> PathBuf {
+        self.dir.join(year.to_string())
+    }
+
+    pub fn puzzle_path(&self, year: u16, day: u8) -> PathBuf {
+        self.year_dir(year).join(format!("day{}.txt", day))
+    }
+
+    /// Reads the input for `day` of `year` from `source`, exactly as written. `normalize`
+    /// happens when a registered day parses it.
+    pub fn load(&self, source: &Source, year: u16, day: u8) -> io::Result<String> {
+        match source {
+            Source::Puzzle => fs::read_to_string(self.puzzle_path(year, day)),
+            Source::File(path) => fs::read_to_string(path),
+            Source::Stdin => {
+                let mut buf = String::new();
+                io::stdin().read_to_string(&mut buf)?;
+                Ok(buf)
+            }
+            Source::Text(text) => Ok(text.clone()),
+        }
+    }
+
+    /// Opens the input for `day` of `year` from `source` to be read a line at a time, without
+    /// reading it all first.
+    pub fn open(&self, source: &Source, year: u16, day: u8) -> io::Result<Box<dyn BufRead>> {
+        Ok(match source {
+            Source::Puzzle => Box::new(BufReader::new(File::open(self.puzzle_path(year, day))?)),
+            Source::File(path) => Box::new(BufReader::new(File::open(path)?)),
+            Source::Stdin => Box::new(io::stdin().lock()),
+            Source::Text(text) => Box::new(Cursor::new(text.clone().into_bytes())),
+        })
+    }
+}
+
+/// Turns CRLF line endings into LF and drops trailing line breaks, so parsers only ever see
+/// `\n` between lines and nothing after the last one.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let trimmed = input.trim_end_matches(['\n', '\r']);
+
+    if trimmed.contains('\r') {
+        Cow::Owned(trimmed.replace("\r\n", "\n"))
+    } else {
+        Cow::Borrowed(trimmed)
+    }
+}
@@ -1,15 +1,17 @@
-use aoc_runner_derive::aoc_lib;
-
-mod day1;
-mod day10;
-mod day11;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-
-aoc_lib! { year = 2022 }
+pub mod answer;
+pub mod answers;
+pub mod error;
+pub mod gen;
+pub mod geom;
+pub mod grid;
+pub mod input;
+pub mod memory;
+pub mod parallel;
+pub mod params;
+pub mod parse;
+pub mod registry;
+pub mod report;
+pub mod solution;
+pub mod stream;
+pub mod viz;
+pub mod y2022;
@@ -1,6 +1,722 @@
-extern crate advent_2022;
-extern crate aoc_runner_derive;
+use advent_2022::answer::Answer;
+use advent_2022::answers;
+use advent_2022::gen;
+use advent_2022::input::{self, Loader, Source};
+use advent_2022::memory::{self, Usage};
+use advent_2022::parallel::{self, Job};
+use advent_2022::params::{self, Override, Params};
+use advent_2022::registry::{self, Entry};
+use advent_2022::report::Report;
+use advent_2022::solution::Solution;
+use advent_2022::viz::{Backend, Gif, Svg, Terminal};
+use advent_2022::y2022;
+use advent_2022::y2022::day1::rebalance::{self, Method};
+use advent_2022::y2022::day1::{Day1, Inventory};
+use std::collections::hash_map::{self, HashMap};
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, Instant};
 
-use aoc_runner_derive::aoc_main;
+const USAGE: &str = "usage: advent-2022 <command> [options]
 
-aoc_main! { lib = advent_2022 }
+commands:
+  run [DAY] [--part N] [--input PATH|-]    solve one day, or every day
+      [--format text|json|csv]             report as text, JSON lines or CSV
+      [--stream]                           or read DAY's input a line at a time, as text
+      [--memory]                           or count what each step allocates, in a
+                                           build with --features count-allocations
+  all [DAY] [--part N] [--jobs N]          solve every day at once on N threads, and
+                                           tabulate the answers and wall and busy time
+  verify                                   check every answer in answers/<year>.txt
+  bench [DAY] [--part N] [--iterations N]  time the generator and solver
+  list                                     list the registered solutions
+  params [DAY]                             print the puzzle parameters as TOML
+  visualize DAY [--input PATH|-]           animate a day's puzzle
+      [--backend terminal|svg|gif]         in the terminal, or as an SVG of the last frame
+      [--output PATH|-] [--delay MS]       or a GIF, written to <year>-day<N>.<ext>
+  inventory [--input PATH|-]               analyse the elves' calories from 2022 day 1
+      [--rebalance N]                      and share the snacks out among N elves
+      [--method exact|lpt|kk]              exactly, greedily, or by largest differencing;
+                                           exact for up to 16 snacks, else kk, by default
+  gen [DAY] [--seed N] [--size N]          generate random valid inputs, written to
+      [--output PATH|-]                    generated/<year>/day<N>-<seed>.txt
+
+every command takes --year N to pick a year other than the latest
+run, all and bench take --param dayN.KEY=VALUE (repeatable) and --params PATH, a TOML
+file of [dayN] tables, to change the numbers the puzzles fix
+puzzle inputs are read from $ADVENT_INPUT_DIR/<year>, or input/<year> if it is unset";
+
+struct Args {
+    command: String,
+    year: u16,
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<String>,
+    iterations: usize,
+    format: Format,
+    backend: String,
+    output: Option<String>,
+    delay: Option<u64>,
+    seed: u64,
+    size: usize,
+    jobs: Option<usize>,
+    stream: bool,
+    memory: bool,
+    overrides: Vec<Override>,
+    rebalance: Option<usize>,
+    method: Option<Method>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args {
+        command: args.next().unwrap_or_else(|| "run".to_string()),
+        year: registry::latest_year(),
+        day: None,
+        part: None,
+        input: None,
+        iterations: 100,
+        format: Format::Text,
+        backend: "terminal".to_string(),
+        output: None,
+        delay: None,
+        seed: 1,
+        size: 1000,
+        jobs: None,
+        stream: false,
+        memory: false,
+        overrides: Vec::new(),
+        rebalance: None,
+        method: None,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| args.next().ok_or(format!("{} needs a value", flag));
+
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let part = value(&arg)?;
+                parsed.part = Some(match part.as_str() {
+                    "1" => 1,
+                    "2" => 2,
+                    _ => return Err(format!("no such part: {}", part)),
+                });
+            }
+            "--year" | "-y" => {
+                let year = value(&arg)?;
+                parsed.year = year
+                    .parse()
+                    .ok()
+                    .filter(|&year| registry::years().any(|y| y == year))
+                    .ok_or(format!("no registered year: {}", year))?;
+            }
+            "--input" | "-i" => parsed.input = Some(value(&arg)?),
+            "--format" | "-f" => {
+                let format = value(&arg)?;
+                parsed.format = match format.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    "csv" => Format::Csv,
+                    _ => return Err(format!("no such format: {}", format)),
+                };
+            }
+            "--iterations" | "-n" => {
+                let n = value(&arg)?;
+                parsed.iterations = n
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or(format!("not a positive number of iterations: {}", n))?;
+            }
+            "--backend" | "-b" => parsed.backend = value(&arg)?,
+            "--output" | "-o" => parsed.output = Some(value(&arg)?),
+            "--delay" => {
+                let ms = value(&arg)?;
+                parsed.delay = Some(
+                    ms.parse()
+                        .map_err(|_| format!("not a delay in milliseconds: {}", ms))?,
+                );
+            }
+            "--jobs" | "-j" => {
+                let jobs = value(&arg)?;
+                parsed.jobs = Some(
+                    jobs.parse()
+                        .ok()
+                        .filter(|&jobs| jobs > 0)
+                        .ok_or(format!("not a positive number of threads: {}", jobs))?,
+                );
+            }
+            "--stream" => parsed.stream = true,
+            "--memory" => parsed.memory = true,
+            "--param" => {
+                let param = value(&arg)?;
+                parsed.overrides.push(
+                    Override::from_arg(&param)
+                        .ok_or(format!("expected dayN.KEY=VALUE: {}", param))?,
+                );
+            }
+            "--params" => {
+                let path = value(&arg)?;
+                let text = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path, e))?;
+                parsed
+                    .overrides
+                    .extend(params::parse_file(&text).map_err(|e| format!("{}: {}", path, e))?);
+            }
+            "--seed" => {
+                let seed = value(&arg)?;
+                parsed.seed = seed.parse().map_err(|_| format!("not a seed: {}", seed))?;
+            }
+            "--size" => {
+                let size = value(&arg)?;
+                parsed.size = size
+                    .parse()
+                    .ok()
+                    .filter(|&size| size > 0)
+                    .ok_or(format!("not a positive size: {}", size))?;
+            }
+            "--rebalance" => {
+                let elves = value(&arg)?;
+                parsed.rebalance = Some(
+                    elves
+                        .parse()
+                        .ok()
+                        .filter(|&elves| elves > 0)
+                        .ok_or(format!("not a positive number of elves: {}", elves))?,
+                );
+            }
+            "--method" => parsed.method = Some(value(&arg)?.parse()?),
+            day if parsed.day.is_none() && !day.starts_with('-') => {
+                parsed.day = Some(day.parse().map_err(|_| format!("not a day: {}", day))?);
+            }
+            _ => return Err(format!("unexpected argument: {}", arg)),
+        }
+    }
+
+    if parsed.input.is_some() && parsed.day.is_none() && parsed.command != "inventory" {
+        return Err("--input needs a day".to_string());
+    }
+
+    Ok(parsed)
+}
+
+fn read_input(year: u16, day: u8, input: Option<&str>) -> io::Result<String> {
+    let source = input.map_or(Source::Puzzle, Source::from_arg);
+
+    Loader::from_env(Path::new("")).load(&source, year, day)
+}
+
+/// Each day's input, read the first time a part of it is solved. Stdin can only be read
+/// once, so every part after the first has to reuse what was read.
+struct Inputs<'a> {
+    input: Option<&'a str>,
+    read: HashMap<(u16, u8), String>,
+}
+
+impl<'a> Inputs<'a> {
+    fn new(args: &'a Args) -> Self {
+        Inputs {
+            input: args.input.as_deref(),
+            read: HashMap::new(),
+        }
+    }
+
+    fn get(&mut self, entry: &Entry) -> Result<&str, String> {
+        let input = match self.read.entry((entry.year, entry.day)) {
+            hash_map::Entry::Occupied(read) => read.into_mut(),
+            hash_map::Entry::Vacant(unread) => unread.insert(
+                read_input(entry.year, entry.day, self.input)
+                    .map_err(|e| format!("{}: could not read input: {}", entry, e))?,
+            ),
+        };
+
+        Ok(input)
+    }
+}
+
+/// `entry`'s parameters with any overrides for its day applied.
+fn params_for(entry: &Entry, args: &Args) -> Result<Box<dyn Params>, String> {
+    let mut params = entry.default_params();
+    params::apply(&mut *params, entry.day, &args.overrides)
+        .map_err(|e| format!("{}: {}", entry, e))?;
+
+    Ok(params)
+}
+
+fn selected(args: &Args) -> Result<Vec<(&'static Entry, u8)>, String> {
+    let selected = registry::select(args.year, args.day)
+        .flat_map(|entry| entry.parts().map(move |part| (entry, part)))
+        .filter(|&(_, part)| args.part.is_none_or(|p| p == part))
+        .collect::<Vec<_>>();
+
+    if selected.is_empty() {
+        Err("no registered solution matches".to_string())
+    } else {
+        Ok(selected)
+    }
+}
+
+/// Starts bitmaps on a line of their own so their rows line up.
+fn display_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Bitmap(_) => format!("\n{}", answer),
+        _ => answer.to_string(),
+    }
+}
+
+fn run(args: &Args) -> Result<bool, String> {
+    if args.stream {
+        return stream(args);
+    }
+    if args.memory {
+        return profile_memory(args);
+    }
+    if args.format != Format::Text {
+        return report(args);
+    }
+
+    let mut ok = true;
+
+    println!("Advent of code {}", args.year);
+
+    let mut inputs = Inputs::new(args);
+    for (entry, part) in selected(args)? {
+        let input = inputs.get(entry)?;
+
+        match entry.run_with(input, part, &*params_for(entry, args)?) {
+            Ok(run) => println!(
+                "{} - Part {}: {}\n\tgenerator: {:?},\n\trunner: {:?}\n",
+                entry,
+                part,
+                display_answer(&run.answer),
+                run.parse_time,
+                run.solve_time
+            ),
+            Err(e) => {
+                eprintln!("{} - Part {}: FAILED: {}\n", entry, part, e);
+                ok = false;
+            }
+        }
+    }
+
+    Ok(ok)
+}
+
+fn stream(args: &Args) -> Result<bool, String> {
+    let day = args.day.ok_or("--stream needs a day")?;
+    if args.format != Format::Text {
+        return Err("--stream only reports as text".to_string());
+    }
+    let entry = registry::find(args.year, day).ok_or(format!("Day {} is not registered", day))?;
+    let params = params_for(entry, args)?;
+    let source = args
+        .input
+        .as_deref()
+        .map_or(Source::Puzzle, Source::from_arg);
+    let mut reader = Loader::from_env(Path::new(""))
+        .open(&source, args.year, day)
+        .map_err(|e| format!("{}: could not read input: {}", entry, e))?;
+
+    let start = Instant::now();
+    let answers = entry
+        .stream_with(&mut reader, &*params)
+        .ok_or(format!("Day {} cannot stream its input", day))?;
+    let time = start.elapsed();
+
+    println!("Advent of code {}", args.year);
+    match answers {
+        Ok(answers) => {
+            for (part, answer) in (1..).zip(&answers) {
+                if args.part.is_none_or(|p| p == part) {
+                    println!("{} - Part {}: {}", entry, part, display_answer(answer));
+                }
+            }
+            println!("\tstreamed: {:?}", time);
+            Ok(true)
+        }
+        Err(e) => {
+            eprintln!("{}: FAILED: {}", entry, e);
+            Ok(false)
+        }
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    match bytes {
+        0..1024 => format!("{} B", bytes),
+        1024..1048576 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / 1048576.0),
+    }
+}
+
+fn profile_memory(args: &Args) -> Result<bool, String> {
+    if !cfg!(feature = "count-allocations") {
+        return Err("--memory needs a build with --features count-allocations".to_string());
+    }
+
+    let row = |entry: &Entry, step: &str, usage: Usage| {
+        println!(
+            "{:<8} {:<10} {:>12} {:>12} {:>12}",
+            entry.to_string(),
+            step,
+            usage.allocations,
+            format_bytes(usage.bytes),
+            format_bytes(usage.peak)
+        )
+    };
+    let mut ok = true;
+
+    println!("Advent of code {}", args.year);
+    println!(
+        "{:<8} {:<10} {:>12} {:>12} {:>12}",
+        "day", "step", "allocations", "bytes", "peak"
+    );
+    for entry in registry::select(args.year, args.day) {
+        let input = read_input(entry.year, entry.day, args.input.as_deref())
+            .map_err(|e| format!("{}: could not read input: {}", entry, e))?;
+
+        let (parsed, usage) = memory::measure(|| entry.parse(&input));
+        row(entry, "generator", usage);
+        let parsed = match parsed {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                eprintln!("{}: FAILED: {}", entry, e);
+                ok = false;
+                continue;
+            }
+        };
+
+        let params = params_for(entry, args)?;
+        for part in entry
+            .parts()
+            .filter(|&p| args.part.is_none_or(|part| part == p))
+        {
+            let (answer, usage) = memory::measure(|| entry.solve_with(&parsed, part, &*params));
+            row(entry, &format!("part {}", part), usage);
+            if let Err(e) = answer {
+                eprintln!("{} - Part {}: FAILED: {}", entry, part, e);
+                ok = false;
+            }
+        }
+    }
+
+    Ok(ok)
+}
+
+fn report(args: &Args) -> Result<bool, String> {
+    let mut ok = true;
+
+    if args.format == Format::Csv {
+        println!("{}", Report::CSV_HEADER);
+    }
+
+    let mut inputs = Inputs::new(args);
+    for (entry, part) in selected(args)? {
+        let input = inputs.get(entry)?;
+
+        let report = Report::run_with(entry, input, part, &*params_for(entry, args)?);
+        ok &= report.result.is_ok();
+
+        match args.format {
+            Format::Json => println!("{}", report.to_json()),
+            _ => println!("{}", report.to_csv()),
+        }
+    }
+
+    Ok(ok)
+}
+
+fn all(args: &Args) -> Result<bool, String> {
+    let jobs = registry::select(args.year, args.day)
+        .map(|entry| {
+            let input = read_input(entry.year, entry.day, args.input.as_deref())
+                .map_err(|e| format!("{}: could not read input: {}", entry, e))?;
+
+            Ok(Job {
+                entry,
+                input,
+                params: params_for(entry, args)?,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    if jobs.is_empty() {
+        return Err("no registered solution matches".to_string());
+    }
+
+    let threads = args
+        .jobs
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, |threads| threads.get()));
+    let summary = parallel::run(&jobs, args.part, threads);
+
+    println!("Advent of code {}", args.year);
+    println!(
+        "{:<8} {:>4} {:>12} {:>12}  answer",
+        "day", "part", "generator", "runner"
+    );
+    let mut ok = true;
+    for outcome in &summary.outcomes {
+        let answer = match &outcome.result {
+            Ok(answer) => display_answer(answer),
+            Err(e) => {
+                ok = false;
+                format!("FAILED: {}", e)
+            }
+        };
+        println!(
+            "{:<8} {:>4} {:>12} {:>12}  {}",
+            outcome.entry.to_string(),
+            outcome.part,
+            format!("{:.2?}", outcome.parse_time),
+            format!("{:.2?}", outcome.solve_time),
+            answer
+        );
+    }
+    println!(
+        "\n{} parts on {} threads: wall {:.2?}, busy {:.2?}",
+        summary.outcomes.len(),
+        summary.threads,
+        summary.wall_time,
+        summary.busy_time
+    );
+
+    Ok(ok)
+}
+
+fn verify(args: &Args) -> Result<bool, String> {
+    let root = Path::new("");
+    let path = input::answers_path(root, args.year);
+    let known = answers::load(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let input_dir = Loader::from_env(root).year_dir(args.year);
+    let mismatches = answers::verify(args.year, &known, &input_dir);
+
+    for (known, mismatch) in &mismatches {
+        println!(
+            "{} Day {} - Part {} ({}): expected {}, {}",
+            args.year, known.day, known.part, known.file, known.answer, mismatch
+        );
+    }
+    println!(
+        "{} of {} known answers for {} match",
+        known.len() - mismatches.len(),
+        known.len(),
+        args.year
+    );
+
+    Ok(mismatches.is_empty())
+}
+
+fn bench(args: &Args) -> Result<bool, String> {
+    let mut inputs = Inputs::new(args);
+    for (entry, part) in selected(args)? {
+        let input = inputs.get(entry)?;
+
+        let params = params_for(entry, args)?;
+        let runs = (0..args.iterations)
+            .map(|_| entry.run_with(input, part, &*params))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("{} - Part {}: FAILED: {}", entry, part, e))?;
+
+        let mean = |time: fn(&registry::Run) -> Duration| {
+            runs.iter().map(time).sum::<Duration>() / runs.len() as u32
+        };
+        let min = |time: fn(&registry::Run) -> Duration| runs.iter().map(time).min().unwrap();
+
+        println!(
+            "{} - Part {} ({} runs)\n\tgenerator: mean {:?}, min {:?}\n\trunner: mean {:?}, min {:?}",
+            entry,
+            part,
+            runs.len(),
+            mean(|r| r.parse_time),
+            min(|r| r.parse_time),
+            mean(|r| r.solve_time),
+            min(|r| r.solve_time),
+        );
+    }
+
+    Ok(true)
+}
+
+fn print_params(args: &Args) -> Result<bool, String> {
+    for entry in registry::select(args.year, args.day) {
+        let params = params_for(entry, args)?;
+        let values = params.values();
+        if values.is_empty() {
+            continue;
+        }
+
+        println!("[day{}]", entry.day);
+        for (key, value) in values {
+            match value {
+                Some(value) => println!("{} = {}", key, value),
+                None => println!("# {} is worked out from the input", key),
+            }
+        }
+        println!();
+    }
+
+    Ok(true)
+}
+
+fn list() -> Result<bool, String> {
+    for entry in registry::entries() {
+        let parts = entry.parts().map(|p| p.to_string()).collect::<Vec<_>>();
+        let visualize = if entry.can_visualize() {
+            ", visualize"
+        } else {
+            ""
+        };
+        println!(
+            "{} {}: parts {}{}",
+            entry.year,
+            entry,
+            parts.join(", "),
+            visualize
+        );
+    }
+
+    Ok(true)
+}
+
+fn visualize(args: &Args) -> Result<bool, String> {
+    let day = args.day.ok_or("visualize needs a day")?;
+    let entry = registry::find(args.year, day).ok_or(format!("Day {} is not registered", day))?;
+    let input = read_input(args.year, day, args.input.as_deref())
+        .map_err(|e| format!("{}: could not read input: {}", entry, e))?;
+
+    let frames = entry
+        .visualize(&input)
+        .ok_or(format!("Day {} has no visualization", day))?
+        .map_err(|e| e.to_string())?;
+
+    let (backend, extension): (Box<dyn Backend>, _) = match args.backend.as_str() {
+        "terminal" => {
+            let mut terminal = Terminal::default();
+            if let Some(ms) = args.delay {
+                terminal.delay = Duration::from_millis(ms);
+            }
+            (Box::new(terminal), None)
+        }
+        "svg" => (Box::new(Svg::default()), Some("svg")),
+        "gif" => {
+            let mut gif = Gif::default();
+            if let Some(ms) = args.delay {
+                gif.delay = u16::try_from(ms / 10).unwrap_or(u16::MAX);
+            }
+            (Box::new(gif), Some("gif"))
+        }
+        backend => return Err(format!("no such backend: {}", backend)),
+    };
+
+    let output = match (args.output.as_deref(), extension) {
+        (Some(path), _) => path.to_string(),
+        (None, Some(extension)) => format!("{}-day{}.{}", args.year, day, extension),
+        (None, None) => "-".to_string(),
+    };
+
+    let written = if output == "-" {
+        backend.render(&frames, &mut io::stdout().lock())
+    } else {
+        File::create(&output).and_then(|file| {
+            let mut out = BufWriter::new(file);
+            backend.render(&frames, &mut out)?;
+            out.flush()
+        })
+    };
+    written.map_err(|e| format!("{}: {}", output, e))?;
+
+    if output != "-" {
+        println!("wrote {} frames to {}", frames.len(), output);
+    }
+
+    Ok(true)
+}
+
+fn inventory(args: &Args) -> Result<bool, String> {
+    let input = read_input(y2022::YEAR, 1, args.input.as_deref())
+        .map_err(|e| format!("could not read input: {}", e))?;
+    // Parsed directly to get at the elves, so normalised here as `Entry::parse` would.
+    let elves = Day1::parse(&input::normalize(&input)).map_err(|e| e.to_string())?;
+
+    print!("{}", Inventory::new(&elves));
+
+    if let Some(count) = args.rebalance {
+        let snacks = elves.iter().map(Vec::len).sum();
+        let method = args.method.unwrap_or(Method::for_snacks(snacks));
+        let shared = rebalance::rebalance(&elves, count, method).map_err(|e| e.to_string())?;
+        print!("\nrebalanced among {} elves\n{}", count, shared);
+    }
+    Ok(true)
+}
+
+fn generate(args: &Args) -> Result<bool, String> {
+    let entries = registry::select(args.year, args.day).collect::<Vec<_>>();
+    if entries.is_empty() {
+        return Err("no registered solution matches".to_string());
+    }
+    if args.output.is_some() && args.day.is_none() {
+        return Err("--output needs a day".to_string());
+    }
+
+    for entry in entries {
+        let input = gen::generate(args.year, entry.day, args.seed, args.size)
+            .ok_or(format!("{} has no generator", entry))?;
+
+        let output = match args.output.as_deref() {
+            Some(path) => path.to_string(),
+            None => format!("generated/{}/day{}-{}.txt", args.year, entry.day, args.seed),
+        };
+
+        if output == "-" {
+            print!("{}", input);
+            continue;
+        }
+        if let Some(dir) = Path::new(&output).parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+        }
+        fs::write(&output, &input).map_err(|e| format!("{}: {}", output, e))?;
+        println!("{}: wrote {} bytes to {}", entry, input.len(), output);
+    }
+
+    Ok(true)
+}
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: memory::Counting = memory::Counting;
+
+fn main() -> ExitCode {
+    let result = parse_args(env::args().skip(1)).and_then(|args| match args.command.as_str() {
+        "run" => run(&args),
+        "all" => all(&args),
+        "verify" => verify(&args),
+        "bench" => bench(&args),
+        "list" => list(),
+        "params" => print_params(&args),
+        "visualize" => visualize(&args),
+        "inventory" => inventory(&args),
+        "gen" => generate(&args),
+        "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            Ok(true)
+        }
+        command => Err(format!("unknown command: {}\n\n{}", command, USAGE)),
+    });
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::from(2)
+        }
+    }
+}
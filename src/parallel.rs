@@ -0,0 +1,204 @@
+//! Runs many days at once on a pool of worker threads.
+//!
+//! Each day is parsed once, then its parts are queued as separate tasks sharing the parsed
+//! input, so a slow part 2 does not hold up the rest of the day's work.
+
+use crate::answer::Answer;
+use crate::error::SolutionError;
+use crate::params::Params;
+use crate::registry::{Entry, Parsed};
+use std::collections::VecDeque;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// A day to solve in a parallel run.
+pub struct Job {
+    pub entry: &'static Entry,
+    pub input: String,
+    pub params: Box<dyn Params>,
+}
+
+impl Job {
+    /// A job solving `input` with the puzzle's own parameters.
+    pub fn new(entry: &'static Entry, input: String) -> Self {
+        Job {
+            entry,
+            input,
+            params: entry.default_params(),
+        }
+    }
+}
+
+/// One part's result from a parallel run.
+pub struct Outcome {
+    pub entry: &'static Entry,
+    pub part: u8,
+    pub result: Result<Answer, SolutionError>,
+    /// The time spent parsing the day's input, shared by all its parts.
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// Every outcome of a parallel run, ordered by year, day and part however the tasks finished.
+pub struct Summary {
+    pub outcomes: Vec<Outcome>,
+    pub threads: usize,
+    /// Elapsed time for the whole run.
+    pub wall_time: Duration,
+    /// The wall time the workers spent on tasks, summed over all threads. Not CPU time: a
+    /// worker the OS has descheduled mid-task still counts as busy.
+    pub busy_time: Duration,
+}
+
+enum Task {
+    Parse(usize),
+    Solve {
+        day: usize,
+        part: u8,
+        parsed: Arc<Parsed>,
+        parse_time: Duration,
+    },
+}
+
+struct Queue {
+    tasks: VecDeque<Task>,
+    /// Tasks queued or still running, which may yet queue more.
+    pending: usize,
+}
+
+/// Solves `part` of each job, or both parts if it is `None`, on `threads` worker threads.
+pub fn run(jobs: &[Job], part: Option<u8>, threads: usize) -> Summary {
+    let threads = threads.max(1);
+    let queue = Mutex::new(Queue {
+        tasks: (0..jobs.len()).map(Task::Parse).collect(),
+        pending: jobs.len(),
+    });
+    let ready = Condvar::new();
+    let outcomes = Mutex::new(Vec::new());
+
+    let parts = |entry: &Entry| {
+        entry
+            .parts()
+            .filter(|&p| part.is_none_or(|part| part == p))
+            .collect::<Vec<_>>()
+    };
+
+    let perform = |task: Task| -> Vec<Task> {
+        match task {
+            Task::Parse(day) => {
+                let Job { entry, input, .. } = &jobs[day];
+                let start = Instant::now();
+                let parsed = entry.parse(input);
+                let parse_time = start.elapsed();
+
+                match parsed {
+                    Ok(parsed) => {
+                        let parsed = Arc::new(parsed);
+                        parts(entry)
+                            .into_iter()
+                            .map(|part| Task::Solve {
+                                day,
+                                part,
+                                parsed: Arc::clone(&parsed),
+                                parse_time,
+                            })
+                            .collect()
+                    }
+                    Err(e) => {
+                        let failed = parts(entry).into_iter().map(|part| Outcome {
+                            entry,
+                            part,
+                            result: Err(e.clone().into()),
+                            parse_time,
+                            solve_time: Duration::ZERO,
+                        });
+                        outcomes.lock().unwrap().extend(failed);
+                        Vec::new()
+                    }
+                }
+            }
+            Task::Solve {
+                day,
+                part,
+                parsed,
+                parse_time,
+            } => {
+                let Job { entry, params, .. } = &jobs[day];
+                let start = Instant::now();
+                let result = entry.solve_with(&parsed, part, &**params);
+                let solve_time = start.elapsed();
+
+                outcomes.lock().unwrap().push(Outcome {
+                    entry,
+                    part,
+                    result,
+                    parse_time,
+                    solve_time,
+                });
+                Vec::new()
+            }
+        }
+    };
+
+    let worker = || {
+        let mut busy = Duration::ZERO;
+
+        loop {
+            let task = {
+                let mut queue = queue.lock().unwrap();
+                loop {
+                    if let Some(task) = queue.tasks.pop_front() {
+                        break task;
+                    }
+                    if queue.pending == 0 {
+                        return busy;
+                    }
+                    queue = ready.wait(queue).unwrap();
+                }
+            };
+
+            // A panicking task must still be counted off, or the other workers wait forever.
+            let start = Instant::now();
+            let next = panic::catch_unwind(AssertUnwindSafe(|| perform(task)));
+            busy += start.elapsed();
+
+            let mut queue = queue.lock().unwrap();
+            queue.pending -= 1;
+            ready.notify_all();
+            match next {
+                Ok(next) => {
+                    queue.pending += next.len();
+                    queue.tasks.extend(next);
+                }
+                Err(payload) => {
+                    drop(queue);
+                    panic::resume_unwind(payload);
+                }
+            }
+        }
+    };
+
+    let start = Instant::now();
+    let busy_time = thread::scope(|scope| {
+        let workers = (0..threads)
+            .map(|_| scope.spawn(worker))
+            .collect::<Vec<_>>();
+        workers
+            .into_iter()
+            .map(|worker| worker.join().unwrap())
+            .sum()
+    });
+    let wall_time = start.elapsed();
+
+    let mut outcomes = outcomes.into_inner().unwrap();
+    outcomes.sort_by_key(|outcome| (outcome.entry.year, outcome.entry.day, outcome.part));
+
+    Summary {
+        outcomes,
+        threads,
+        wall_time,
+        busy_time,
+    }
+}
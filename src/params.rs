@@ -0,0 +1,202 @@
+//! Puzzle parameters: the numbers a day's puzzle text fixes, such as how many rounds to play,
+//! made overridable so what-if variants can be run.
+//!
+//! Overrides come from `--param dayN.key=value` on the command line, or from a file of
+//! TOML tables, one per day:
+//!
+//! ```toml
+//! [day7]
+//! disk_size = 80_000_000
+//!
+//! [day10]
+//! cycles = [20, 60, 100]
+//! ```
+
+use std::any::Any;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A day's parameters, settable by name from text.
+pub trait Params: fmt::Debug + Send + Sync + 'static {
+    /// Sets the parameter `key` from `value`, a number or a list of numbers.
+    fn set(&mut self, key: &str, value: &str) -> Result<(), ParamError>;
+
+    /// Every parameter and its value written as TOML, or `None` if it has no value until it
+    /// is worked out from the input.
+    fn values(&self) -> Vec<(&'static str, Option<String>)>;
+
+    fn as_any(&self) -> &dyn Any;
+}
+
+/// For days with nothing to tune.
+impl Params for () {
+    fn set(&mut self, key: &str, _: &str) -> Result<(), ParamError> {
+        Err(ParamError::UnknownKey(key.to_string()))
+    }
+
+    fn values(&self) -> Vec<(&'static str, Option<String>)> {
+        Vec::new()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    UnknownKey(String),
+    InvalidValue {
+        key: String,
+        value: String,
+    },
+    /// A line of a parameters file that is not a `[dayN]` header or a `key = value` pair.
+    Syntax {
+        line: usize,
+        text: String,
+    },
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamError::UnknownKey(key) => write!(f, "no parameter called {:?}", key),
+            ParamError::InvalidValue { key, value } => {
+                write!(f, "invalid value for {}: {:?}", key, value)
+            }
+            ParamError::Syntax { line, text } => {
+                write!(
+                    f,
+                    "line {}: expected [dayN] or key = value at {:?}",
+                    line, text
+                )
+            }
+        }
+    }
+}
+
+impl Error for ParamError {}
+
+fn invalid(key: &str, value: &str) -> ParamError {
+    ParamError::InvalidValue {
+        key: key.to_string(),
+        value: value.to_string(),
+    }
+}
+
+/// Parses a number, which may use `_` between digits as TOML allows.
+pub fn number<T: FromStr>(key: &str, value: &str) -> Result<T, ParamError> {
+    value
+        .trim()
+        .replace('_', "")
+        .parse()
+        .map_err(|_| invalid(key, value))
+}
+
+/// Parses a number that must be above zero.
+pub fn positive<T: FromStr + PartialOrd + Default>(
+    key: &str,
+    value: &str,
+) -> Result<T, ParamError> {
+    let n = number(key, value)?;
+
+    if n > T::default() {
+        Ok(n)
+    } else {
+        Err(invalid(key, value))
+    }
+}
+
+/// Parses a TOML array of numbers such as `[20, 60]`, or a bare `20,60` as is easier to
+/// write on the command line.
+pub fn list<T: FromStr>(key: &str, value: &str) -> Result<Vec<T>, ParamError> {
+    let trimmed = value.trim();
+    let items = trimmed
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .unwrap_or(trimmed)
+        .trim();
+
+    if items.is_empty() {
+        return Ok(Vec::new());
+    }
+    items
+        .split(',')
+        .map(|item| number(key, item).map_err(|_| invalid(key, value)))
+        .collect()
+}
+
+/// Writes a list as a TOML array.
+pub fn toml_list<T: fmt::Display>(items: &[T]) -> String {
+    let items = items
+        .iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<_>>();
+
+    format!("[{}]", items.join(", "))
+}
+
+/// One parameter to change for one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Override {
+    pub day: u8,
+    pub key: String,
+    pub value: String,
+}
+
+impl Override {
+    /// Reads `dayN.key=value`, as given to `--param`.
+    pub fn from_arg(arg: &str) -> Option<Self> {
+        let (name, value) = arg.split_once('=')?;
+        let (day, key) = name.split_once('.')?;
+
+        Some(Override {
+            day: day.strip_prefix("day")?.parse().ok()?,
+            key: key.trim().to_string(),
+            value: value.trim().to_string(),
+        })
+    }
+}
+
+/// Reads a parameters file: `[dayN]` tables of `key = value` pairs, with `#` comments.
+pub fn parse_file(text: &str) -> Result<Vec<Override>, ParamError> {
+    let mut day = None;
+    let mut overrides = Vec::new();
+
+    for (i, line) in text.lines().enumerate() {
+        let syntax = || ParamError::Syntax {
+            line: i + 1,
+            text: line.to_string(),
+        };
+        // No value has strings in it, so a `#` always starts a comment.
+        let line = line.split('#').next().unwrap_or("").trim();
+
+        if line.is_empty() {
+            continue;
+        } else if let Some(table) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            let n = table
+                .trim()
+                .strip_prefix("day")
+                .and_then(|n| n.parse().ok());
+            day = Some(n.ok_or_else(syntax)?);
+        } else {
+            let (key, value) = line.split_once('=').ok_or_else(syntax)?;
+            overrides.push(Override {
+                day: day.ok_or_else(syntax)?,
+                key: key.trim().to_string(),
+                value: value.trim().to_string(),
+            });
+        }
+    }
+
+    Ok(overrides)
+}
+
+/// Applies the overrides meant for `day`, ignoring the rest.
+pub fn apply(params: &mut dyn Params, day: u8, overrides: &[Override]) -> Result<(), ParamError> {
+    overrides
+        .iter()
+        .filter(|o| o.day == day)
+        .try_for_each(|o| params.set(&o.key, &o.value))
+}
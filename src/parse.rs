@@ -0,0 +1,363 @@
+//! Small parser combinators for puzzle inputs.
+//!
+//! A parser reads from an `Input`, advancing it past whatever it consumed, and fails with a
+//! `ParseError` located at the offending text in the whole input. Plain functions and closures
+//! taking `&mut Input` are parsers, so a day's grammar reads top to bottom:
+//!
+//! ```text
+//! fn range(input: &mut Input) -> Result<IRange, ParseError> {
+//!     let start = uint().parse(input)?;
+//!     literal("-").parse(input)?;
+//!     let end = uint().parse(input)?;
+//!     Ok(IRange(start, end))
+//! }
+//! ```
+
+use crate::error::{ParseError, ParseErrorKind};
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+/// Byte offsets into the whole input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+/// A parsed value alongside the text it was parsed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub span: Span,
+}
+
+/// What is left to parse of one day's input.
+#[derive(Debug, Clone, Copy)]
+pub struct Input<'a> {
+    day: u8,
+    source: &'a str,
+    pos: usize,
+    end: usize,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(day: u8, source: &'a str) -> Self {
+        Input {
+            day,
+            source,
+            pos: 0,
+            end: source.len(),
+        }
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.source[self.pos..self.end]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.end
+    }
+
+    /// How far into the whole input this is, in bytes.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// The span from `start`, an earlier `position`, up to here.
+    pub fn span_from(&self, start: usize) -> Span {
+        Span {
+            start,
+            end: self.pos,
+        }
+    }
+
+    /// Consumes `prefix` if the input starts with it.
+    pub fn eat(&mut self, prefix: &str) -> bool {
+        let found = self.rest().starts_with(prefix);
+        if found {
+            self.pos += prefix.len();
+        }
+        found
+    }
+
+    /// An error located at `span`.
+    pub fn error_at(&self, span: Span, kind: ParseErrorKind) -> ParseError {
+        ParseError::at(
+            self.day,
+            self.source,
+            &self.source[span.start..span.end],
+            kind,
+        )
+    }
+
+    /// An error for finding something other than `expected` here, quoting the rest of the
+    /// current word.
+    pub fn unexpected(&self, expected: &'static str) -> ParseError {
+        let rest = self.rest();
+        if rest.is_empty() {
+            return self.error_at(
+                self.span_from(self.pos),
+                ParseErrorKind::UnexpectedEnd(expected),
+            );
+        }
+
+        let len = match rest.find(char::is_whitespace) {
+            Some(0) => rest.chars().next().map_or(0, char::len_utf8),
+            Some(len) => len,
+            None => rest.len(),
+        };
+
+        self.error_at(
+            Span {
+                start: self.pos,
+                end: self.pos + len,
+            },
+            ParseErrorKind::Unexpected(expected),
+        )
+    }
+
+    fn take_while(&mut self, pred: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !pred(c)).unwrap_or(rest.len());
+
+        self.pos += len;
+        &rest[..len]
+    }
+
+    /// This input cut off `len` bytes from here.
+    fn bounded(&self, len: usize) -> Input<'a> {
+        Input {
+            end: self.pos + len,
+            ..*self
+        }
+    }
+
+    fn expect_end(&self, expected: &'static str) -> Result<(), ParseError> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.unexpected(expected))
+        }
+    }
+}
+
+pub trait Parser<'a, T> {
+    fn parse(&self, input: &mut Input<'a>) -> Result<T, ParseError>;
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(&mut Input<'a>) -> Result<T, ParseError>,
+{
+    fn parse(&self, input: &mut Input<'a>) -> Result<T, ParseError> {
+        self(input)
+    }
+}
+
+/// Runs `parser` over the whole of `source`, failing if anything is left over.
+pub fn parse_all<'a, T>(
+    day: u8,
+    source: &'a str,
+    parser: impl Parser<'a, T>,
+) -> Result<T, ParseError> {
+    let mut input = Input::new(day, source);
+    let value = parser.parse(&mut input)?;
+
+    input.expect_end("end of input")?;
+    Ok(value)
+}
+
+/// An unsigned decimal integer.
+pub fn uint<'a, T>() -> impl Parser<'a, T>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    move |input: &mut Input<'a>| {
+        let start = input.position();
+        let digits = input.take_while(|c| c.is_ascii_digit());
+
+        if digits.is_empty() {
+            return Err(input.unexpected("an integer"));
+        }
+        digits
+            .parse()
+            .map_err(|e| input.error_at(input.span_from(start), ParseErrorKind::InvalidInt(e)))
+    }
+}
+
+/// A decimal integer with an optional leading `-`.
+pub fn int<'a, T>() -> impl Parser<'a, T>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    move |input: &mut Input<'a>| {
+        let start = input.position();
+        input.eat("-");
+        let digits = input.take_while(|c| c.is_ascii_digit());
+
+        if digits.is_empty() {
+            input.pos = start;
+            return Err(input.unexpected("an integer"));
+        }
+        let span = input.span_from(start);
+        input.source[span.start..span.end]
+            .parse()
+            .map_err(|e| input.error_at(span, ParseErrorKind::InvalidInt(e)))
+    }
+}
+
+/// Exactly the text `lit`.
+pub fn literal<'a>(lit: &'static str) -> impl Parser<'a, ()> {
+    move |input: &mut Input<'a>| {
+        if input.eat(lit) {
+            Ok(())
+        } else {
+            Err(input.unexpected(lit))
+        }
+    }
+}
+
+/// Whichever of `choices`' keywords comes next, as its value. The first match wins.
+pub fn one_of<'a, T: Clone>(
+    choices: &'static [(&'static str, T)],
+    expected: &'static str,
+) -> impl Parser<'a, T> {
+    move |input: &mut Input<'a>| {
+        choices
+            .iter()
+            .find(|(keyword, _)| input.eat(keyword))
+            .map(|(_, value)| value.clone())
+            .ok_or_else(|| input.unexpected(expected))
+    }
+}
+
+/// One or more `item`s with `sep` between them.
+pub fn separated<'a, T>(item: impl Parser<'a, T>, sep: &'static str) -> impl Parser<'a, Vec<T>> {
+    move |input: &mut Input<'a>| {
+        let mut items = vec![item.parse(input)?];
+
+        while input.eat(sep) {
+            items.push(item.parse(input)?);
+        }
+
+        Ok(items)
+    }
+}
+
+/// `item` over a single line, which it must use up, and the line break after it.
+pub fn line<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |input: &mut Input<'a>| {
+        let len = input.rest().find('\n').unwrap_or(input.rest().len());
+        let mut line = input.bounded(len);
+
+        let value = item.parse(&mut line)?;
+        line.expect_end("end of line")?;
+
+        input.pos = line.pos;
+        input.eat("\n");
+        Ok(value)
+    }
+}
+
+/// `item` on every line up to a blank line or the end of the input.
+pub fn lines<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    let item = line(item);
+
+    move |input: &mut Input<'a>| {
+        let mut items = Vec::new();
+
+        while !input.is_empty() && !input.rest().starts_with('\n') {
+            items.push(item.parse(input)?);
+        }
+
+        Ok(items)
+    }
+}
+
+/// `item` over everything up to the next blank line, which it must use up, and the blank
+/// line after it.
+pub fn block<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |input: &mut Input<'a>| {
+        let len = input.rest().find("\n\n").unwrap_or(input.rest().len());
+        let mut block = input.bounded(len);
+
+        let value = item.parse(&mut block)?;
+        block.expect_end("end of block")?;
+
+        input.pos = block.pos;
+        input.eat("\n\n");
+        Ok(value)
+    }
+}
+
+/// `item` on every blank-line separated block up to the end of the input.
+pub fn blocks<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    let item = block(item);
+
+    move |input: &mut Input<'a>| {
+        let mut items = vec![item.parse(input)?];
+
+        while !input.is_empty() {
+            items.push(item.parse(input)?);
+        }
+
+        Ok(items)
+    }
+}
+
+/// All of the remaining input, handed to `T::from_str`, with its errors located within the
+/// whole input.
+pub fn from_str<'a, T>() -> impl Parser<'a, T>
+where
+    T: FromStr<Err = ParseError>,
+{
+    move |input: &mut Input<'a>| {
+        let text = input.rest();
+        let value = text
+            .parse()
+            .map_err(|e: ParseError| e.inside(input.source, text))?;
+
+        input.pos = input.end;
+        Ok(value)
+    }
+}
+
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+    move |input: &mut Input<'a>| parser.parse(input).map(&f)
+}
+
+/// `parser`'s value checked by `f`, failing at the value's span if `f` rejects it.
+pub fn try_map<'a, T, U>(
+    parser: impl Parser<'a, T>,
+    f: impl Fn(T) -> Result<U, ParseErrorKind>,
+) -> impl Parser<'a, U> {
+    move |input: &mut Input<'a>| {
+        let start = input.position();
+        let value = parser.parse(input)?;
+
+        f(value).map_err(|kind| input.error_at(input.span_from(start), kind))
+    }
+}
+
+/// `parser`'s value alongside the span it was parsed from.
+pub fn spanned<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Spanned<T>> {
+    move |input: &mut Input<'a>| {
+        let start = input.position();
+        let value = parser.parse(input)?;
+
+        Ok(Spanned {
+            value,
+            span: input.span_from(start),
+        })
+    }
+}
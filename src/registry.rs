@@ -0,0 +1,239 @@
+use crate::answer::Answer;
+use crate::error::{ParseError, SolutionError, StreamError};
+use crate::input;
+use crate::params::Params;
+use crate::solution::Solution;
+use crate::viz::Frame;
+use crate::y2022;
+use std::any::Any;
+use std::fmt;
+use std::io::BufRead;
+use std::time::{Duration, Instant};
+
+type AnyParsed = Box<dyn Any + Send + Sync>;
+type Part = fn(&AnyParsed, &dyn Params) -> Result<Answer, SolutionError>;
+type Visualizer = fn(&str) -> Result<Vec<Frame>, SolutionError>;
+type Streamer = fn(&mut dyn BufRead, &dyn Params) -> Result<[Answer; 2], StreamError>;
+
+/// A registered day, with its `Solution`'s types erased so every day can be called alike.
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    parse: fn(&str) -> Result<AnyParsed, ParseError>,
+    parts: [Part; 2],
+    params: fn() -> Box<dyn Params>,
+    visualize: Option<Visualizer>,
+    stream: Option<Streamer>,
+}
+
+/// An input parsed by a registry `Entry`, ready to be handed back to the same entry's parts.
+pub struct Parsed {
+    year: u16,
+    day: u8,
+    value: AnyParsed,
+}
+
+/// The answer from running one part, with the time spent parsing and solving.
+pub struct Run {
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+fn parse<S: Solution>(input: &str) -> Result<AnyParsed, ParseError> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn params<S: Solution>() -> Box<dyn Params> {
+    Box::new(S::Params::default())
+}
+
+/// The day's own parameters back from the ones its `Entry` handed out.
+pub(crate) fn params_of<P: 'static>(params: &dyn Params) -> &P {
+    params
+        .as_any()
+        .downcast_ref()
+        .expect("parameters for another day")
+}
+
+fn part1<S: Solution>(parsed: &AnyParsed, params: &dyn Params) -> Result<Answer, SolutionError> {
+    let parsed = parsed.downcast_ref().expect("parsed by another day");
+
+    Ok(S::part1(parsed, params_of(params))?)
+}
+
+fn part2<S: Solution>(parsed: &AnyParsed, params: &dyn Params) -> Result<Answer, SolutionError> {
+    let parsed = parsed.downcast_ref().expect("parsed by another day");
+
+    Ok(S::part2(parsed, params_of(params))?)
+}
+
+impl Entry {
+    pub const fn of<S: Solution>() -> Self {
+        Entry {
+            year: S::YEAR,
+            day: S::DAY,
+            parse: parse::<S>,
+            parts: [part1::<S>, part2::<S>],
+            params: params::<S>,
+            visualize: None,
+            stream: None,
+        }
+    }
+
+    pub(crate) const fn with_visualization(mut self, visualize: Visualizer) -> Self {
+        self.visualize = Some(visualize);
+        self
+    }
+
+    pub(crate) const fn with_streaming(mut self, stream: Streamer) -> Self {
+        self.stream = Some(stream);
+        self
+    }
+
+    pub fn parts(&self) -> impl Iterator<Item = u8> {
+        1..=self.parts.len() as u8
+    }
+
+    /// Parses `input` once it has been through `input::normalize`.
+    pub fn parse(&self, input: &str) -> Result<Parsed, ParseError> {
+        Ok(Parsed {
+            year: self.year,
+            day: self.day,
+            value: (self.parse)(&input::normalize(input))?,
+        })
+    }
+
+    /// The puzzle's own parameters, to override before passing to `solve_with` or
+    /// `run_with`.
+    pub fn default_params(&self) -> Box<dyn Params> {
+        (self.params)()
+    }
+
+    fn part(&self, part: u8) -> Result<Part, SolutionError> {
+        usize::from(part)
+            .checked_sub(1)
+            .and_then(|index| self.parts.get(index).copied())
+            .ok_or(SolutionError::NoSuchPart {
+                day: self.day,
+                part,
+            })
+    }
+
+    /// Solves `part` (1 or 2) of an input this entry parsed, with the default parameters.
+    /// Any other part is a `SolutionError::NoSuchPart`.
+    pub fn solve(&self, parsed: &Parsed, part: u8) -> Result<Answer, SolutionError> {
+        self.solve_with(parsed, part, &*self.default_params())
+    }
+
+    /// Solves `part` with `params`, which must have come from this entry's `default_params`.
+    pub fn solve_with(
+        &self,
+        parsed: &Parsed,
+        part: u8,
+        params: &dyn Params,
+    ) -> Result<Answer, SolutionError> {
+        assert!(
+            (parsed.year, parsed.day) == (self.year, self.day),
+            "{} {} cannot solve input parsed by {} day {}",
+            self.year,
+            self,
+            parsed.year,
+            parsed.day
+        );
+
+        let solve = self.part(part)?;
+        solve(&parsed.value, params)
+    }
+
+    /// Parses `input` and solves `part`, timing each step.
+    pub fn run(&self, input: &str, part: u8) -> Result<Run, SolutionError> {
+        self.run_with(input, part, &*self.default_params())
+    }
+
+    /// Parses `input` and solves `part` with `params`, timing each step.
+    pub fn run_with(
+        &self,
+        input: &str,
+        part: u8,
+        params: &dyn Params,
+    ) -> Result<Run, SolutionError> {
+        // Checked first so an unknown part is not reported after a slow parse.
+        self.part(part)?;
+
+        let start_time = Instant::now();
+        let parsed = self.parse(input)?;
+        let inter_time = Instant::now();
+        let answer = self.solve_with(&parsed, part, params)?;
+        let final_time = Instant::now();
+
+        Ok(Run {
+            answer,
+            parse_time: inter_time - start_time,
+            solve_time: final_time - inter_time,
+        })
+    }
+
+    pub fn can_visualize(&self) -> bool {
+        self.visualize.is_some()
+    }
+
+    pub fn can_stream(&self) -> bool {
+        self.stream.is_some()
+    }
+
+    /// Solves both parts while reading `reader` a line at a time, for the days that can, with
+    /// the default parameters.
+    pub fn stream(&self, reader: &mut dyn BufRead) -> Option<Result<[Answer; 2], StreamError>> {
+        self.stream_with(reader, &*self.default_params())
+    }
+
+    /// Streams with `params`, which must have come from this entry's `default_params`.
+    pub fn stream_with(
+        &self,
+        reader: &mut dyn BufRead,
+        params: &dyn Params,
+    ) -> Option<Result<[Answer; 2], StreamError>> {
+        self.stream.map(|stream| stream(reader, params))
+    }
+
+    /// Animates the puzzle, for the days that have an animation.
+    pub fn visualize(&self, input: &str) -> Option<Result<Vec<Frame>, SolutionError>> {
+        self.visualize
+            .map(|visualize| visualize(&input::normalize(input)))
+    }
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {}", self.day)
+    }
+}
+
+/// Every year's registered days, oldest year first.
+static YEARS: &[&[Entry]] = &[y2022::ENTRIES];
+
+pub fn entries() -> impl Iterator<Item = &'static Entry> {
+    YEARS.iter().flat_map(|entries| entries.iter())
+}
+
+/// The years with registered days, oldest first.
+pub fn years() -> impl Iterator<Item = u16> {
+    YEARS
+        .iter()
+        .filter_map(|entries| entries.first())
+        .map(|e| e.year)
+}
+
+pub fn latest_year() -> u16 {
+    years().last().expect("no years registered")
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Entry> {
+    entries().find(|e| e.year == year && e.day == day)
+}
+
+/// The registered days of `year`, or just `day` if one is given.
+pub fn select(year: u16, day: Option<u8>) -> impl Iterator<Item = &'static Entry> {
+    entries().filter(move |e| e.year == year && day.is_none_or(|day| e.day == day))
+}
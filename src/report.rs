@@ -0,0 +1,134 @@
+use crate::answer::json_string;
+use crate::error::SolutionError;
+use crate::params::Params;
+use crate::registry::{Entry, Run};
+use std::fmt;
+
+/// One part's result, in a shape meant for dashboards rather than people.
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: InputHash,
+    pub result: Result<Run, SolutionError>,
+}
+
+/// A 64-bit FNV-1a hash of an input file, enough to tell whether two runs saw the same input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InputHash(pub u64);
+
+impl InputHash {
+    pub fn of(input: &str) -> Self {
+        let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+
+        InputHash(hash)
+    }
+}
+
+impl fmt::Display for InputHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "fnv1a64:{:016x}", self.0)
+    }
+}
+
+impl Report {
+    /// Runs `part` of `entry` over `input` and records the outcome.
+    pub fn run(entry: &Entry, input: &str, part: u8) -> Self {
+        Report::run_with(entry, input, part, &*entry.default_params())
+    }
+
+    /// Runs `part` of `entry` over `input` with `params` and records the outcome.
+    pub fn run_with(entry: &Entry, input: &str, part: u8, params: &dyn Params) -> Self {
+        Report {
+            year: entry.year,
+            day: entry.day,
+            part,
+            input_hash: InputHash::of(input),
+            result: entry.run_with(input, part, params),
+        }
+    }
+
+    /// `ok`, or which step failed: `parse_error` or `solve_error`, or `no_such_part`.
+    pub fn status(&self) -> &'static str {
+        match &self.result {
+            Ok(_) => "ok",
+            Err(SolutionError::Parse(_)) => "parse_error",
+            Err(SolutionError::Solve(_)) => "solve_error",
+            Err(SolutionError::NoSuchPart { .. }) => "no_such_part",
+        }
+    }
+
+    /// Serialises the report as a single line of JSON. Timings are in nanoseconds.
+    pub fn to_json(&self) -> String {
+        let outcome = match &self.result {
+            Ok(run) => format!(
+                r#""answer":{},"parse_ns":{},"solve_ns":{}"#,
+                run.answer.to_json(),
+                run.parse_time.as_nanos(),
+                run.solve_time.as_nanos()
+            ),
+            Err(e) => format!(r#""error":{}"#, json_string(&e.to_string())),
+        };
+
+        format!(
+            r#"{{"year":{},"day":{},"part":{},"status":"{}","input_hash":"{}",{}}}"#,
+            self.year,
+            self.day,
+            self.part,
+            self.status(),
+            self.input_hash,
+            outcome
+        )
+    }
+
+    pub const CSV_HEADER: &'static str =
+        "year,day,part,status,input_hash,answer_type,answer,parse_ns,solve_ns,error";
+
+    /// Serialises the report as a CSV record matching `CSV_HEADER`. Fields that hold commas,
+    /// quotes or line breaks, such as bitmap answers, are quoted.
+    pub fn to_csv(&self) -> String {
+        let (answer_type, answer, parse_ns, solve_ns, error) = match &self.result {
+            Ok(run) => (
+                run.answer.kind(),
+                run.answer.to_string(),
+                run.parse_time.as_nanos().to_string(),
+                run.solve_time.as_nanos().to_string(),
+                String::new(),
+            ),
+            Err(e) => (
+                "",
+                String::new(),
+                String::new(),
+                String::new(),
+                e.to_string(),
+            ),
+        };
+
+        [
+            self.year.to_string(),
+            self.day.to_string(),
+            self.part.to_string(),
+            self.status().to_string(),
+            self.input_hash.to_string(),
+            answer_type.to_string(),
+            answer,
+            parse_ns,
+            solve_ns,
+            error,
+        ]
+        .iter()
+        .map(|field| csv_field(field))
+        .collect::<Vec<_>>()
+        .join(",")
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
@@ -0,0 +1,20 @@
+use crate::answer::Answer;
+use crate::error::{ParseError, SolveError};
+use crate::params::Params;
+
+/// One day's puzzle: a parser shared by both parts, and the two parts themselves.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Parsed: Send + Sync + 'static;
+
+    /// The numbers the puzzle fixes, defaulting to the puzzle's own; `()` if there are none.
+    type Params: Params + Default;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+
+    fn part1(parsed: &Self::Parsed, params: &Self::Params) -> Result<Answer, SolveError>;
+
+    fn part2(parsed: &Self::Parsed, params: &Self::Params) -> Result<Answer, SolveError>;
+}
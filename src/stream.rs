@@ -0,0 +1,58 @@
+//! Reading puzzle inputs a line at a time, for the days that can be solved without holding
+//! the whole input in memory.
+
+use crate::error::{ParseError, StreamError};
+use std::io::BufRead;
+use std::str::FromStr;
+
+/// Calls `each` with every line of `reader` and its 0-based index, without its line ending.
+/// One buffer is reused for every line. Trailing blank lines are dropped, as
+/// `input::normalize` drops them.
+pub fn for_each_line<E>(
+    mut reader: impl BufRead,
+    mut each: impl FnMut(usize, &str) -> Result<(), E>,
+) -> Result<(), E>
+where
+    E: From<std::io::Error>,
+{
+    let mut buf = String::new();
+    let mut index = 0;
+    // Blank lines are held back until a line follows them.
+    let mut blanks = 0;
+
+    loop {
+        buf.clear();
+        if reader.read_line(&mut buf)? == 0 {
+            return Ok(());
+        }
+
+        let line = buf.strip_suffix('\n').unwrap_or(&buf);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if line.is_empty() {
+            blanks += 1;
+            continue;
+        }
+
+        for _ in 0..blanks {
+            each(index, "")?;
+            index += 1;
+        }
+        blanks = 0;
+
+        each(index, line)?;
+        index += 1;
+    }
+}
+
+/// Parses every line of `reader` with `T::from_str`, handing each to `each` as it is read.
+pub fn for_each_parsed<T>(
+    reader: impl BufRead,
+    mut each: impl FnMut(T) -> Result<(), StreamError>,
+) -> Result<(), StreamError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    for_each_line(reader, |index, line| {
+        each(line.parse().map_err(|e: ParseError| e.below(index))?)
+    })
+}
@@ -0,0 +1,69 @@
+use super::{colour_index, Backend, Frame, PALETTE};
+use ::gif::{Encoder, Repeat};
+use std::io::{self, Write};
+use std::iter;
+
+/// Encodes the frames as a looping animated GIF, `cell_size` pixels per cell and `delay`
+/// hundredths of a second between frames.
+#[derive(Debug, Clone, Copy)]
+pub struct Gif {
+    pub cell_size: usize,
+    pub delay: u16,
+}
+
+impl Default for Gif {
+    fn default() -> Self {
+        Gif {
+            cell_size: 4,
+            delay: 5,
+        }
+    }
+}
+
+fn too_large() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, "frames too large for a GIF")
+}
+
+impl Backend for Gif {
+    fn render(&self, frames: &[Frame], out: &mut dyn Write) -> io::Result<()> {
+        let (cells_wide, cells_high) = match frames.first() {
+            Some(frame) => (frame.cells.width(), frame.cells.height()),
+            None => return Err(io::Error::new(io::ErrorKind::InvalidInput, "no frames")),
+        };
+        let width = u16::try_from(cells_wide * self.cell_size).map_err(|_| too_large())?;
+        let height = u16::try_from(cells_high * self.cell_size).map_err(|_| too_large())?;
+
+        let palette = PALETTE.concat();
+        let mut encoder = Encoder::new(out, width, height, &palette).map_err(io::Error::other)?;
+        encoder
+            .set_repeat(Repeat::Infinite)
+            .map_err(io::Error::other)?;
+
+        for frame in frames {
+            let cells = frame.flatten();
+            if (cells.width(), cells.height()) != (cells_wide, cells_high) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "frames of different sizes",
+                ));
+            }
+
+            let mut pixels = Vec::with_capacity(usize::from(width) * usize::from(height));
+            for row in cells.rows() {
+                let line = row
+                    .iter()
+                    .flat_map(|&glyph| iter::repeat_n(colour_index(glyph), self.cell_size))
+                    .collect::<Vec<_>>();
+                for _ in 0..self.cell_size {
+                    pixels.extend_from_slice(&line);
+                }
+            }
+
+            let mut image = ::gif::Frame::from_indexed_pixels(width, height, pixels, None);
+            image.delay = self.delay;
+            encoder.write_frame(&image).map_err(io::Error::other)?;
+        }
+
+        Ok(())
+    }
+}
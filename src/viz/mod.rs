@@ -0,0 +1,86 @@
+//! Animations of puzzle simulations.
+//!
+//! A simulation emits `Frame`s: a grid of characters with sprites drawn on top. A `Backend`
+//! turns the frames into something to look at.
+
+mod gif;
+mod svg;
+mod terminal;
+
+pub use self::gif::Gif;
+pub use self::svg::Svg;
+pub use self::terminal::Terminal;
+
+use crate::geom::Point2;
+use crate::grid::Grid;
+use std::io::{self, Write};
+
+/// A character drawn over a frame's cells, such as a rope's knot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sprite {
+    pub pos: Point2,
+    pub glyph: char,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub cells: Grid<char>,
+    pub sprites: Vec<Sprite>,
+}
+
+impl Frame {
+    pub fn new(cells: Grid<char>) -> Self {
+        Frame {
+            cells,
+            sprites: Vec::new(),
+        }
+    }
+
+    pub fn with_sprite(mut self, pos: Point2, glyph: char) -> Self {
+        self.sprites.push(Sprite { pos, glyph });
+        self
+    }
+
+    /// The cells with the sprites drawn over them, later sprites on top. Sprites outside the
+    /// grid are left out.
+    pub fn flatten(&self) -> Grid<char> {
+        let mut cells = self.cells.clone();
+
+        for sprite in &self.sprites {
+            if cells.contains(sprite.pos) {
+                cells[(sprite.pos.x as usize, sprite.pos.y as usize)] = sprite.glyph;
+            }
+        }
+
+        cells
+    }
+}
+
+/// Somewhere to render frames to.
+pub trait Backend {
+    fn render(&self, frames: &[Frame], out: &mut dyn Write) -> io::Result<()>;
+}
+
+/// The colours glyphs are drawn in, shared by every backend so a day looks the same in each.
+const PALETTE: [[u8; 3]; 5] = [
+    [0x0f, 0x0f, 0x23],
+    [0xff, 0xff, 0x66],
+    [0x00, 0xcc, 0x00],
+    [0xcc, 0xcc, 0xcc],
+    [0x99, 0x99, 0xcc],
+];
+
+/// Which `PALETTE` colour to draw `glyph` in. Blanks are the background.
+fn colour_index(glyph: char) -> u8 {
+    match glyph {
+        ' ' | '.' => 0,
+        '#' => 1,
+        c if c.is_ascii_alphabetic() => 2,
+        c if c.is_ascii_digit() => 3,
+        _ => 4,
+    }
+}
+
+fn colour(glyph: char) -> [u8; 3] {
+    PALETTE[usize::from(colour_index(glyph))]
+}
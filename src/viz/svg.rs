@@ -0,0 +1,72 @@
+use super::{colour, colour_index, Backend, Frame, PALETTE};
+use std::io::{self, Write};
+
+/// Draws the last frame as a static SVG image, `cell_size` pixels per cell.
+#[derive(Debug, Clone, Copy)]
+pub struct Svg {
+    pub cell_size: usize,
+}
+
+impl Default for Svg {
+    fn default() -> Self {
+        Svg { cell_size: 10 }
+    }
+}
+
+fn hex([r, g, b]: [u8; 3]) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+impl Backend for Svg {
+    fn render(&self, frames: &[Frame], out: &mut dyn Write) -> io::Result<()> {
+        let cells = match frames.last() {
+            Some(frame) => frame.flatten(),
+            None => return Err(io::Error::new(io::ErrorKind::InvalidInput, "no frames")),
+        };
+        let size = self.cell_size;
+        let (width, height) = (cells.width() * size, cells.height() * size);
+
+        writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+            width, height
+        )?;
+        writeln!(
+            out,
+            r#"<rect width="{}" height="{}" fill="{}"/>"#,
+            width,
+            height,
+            hex(PALETTE[0])
+        )?;
+
+        for (x, y) in cells.positions() {
+            let glyph = cells[(x, y)];
+            if colour_index(glyph) == 0 {
+                continue;
+            }
+
+            if glyph.is_ascii_alphanumeric() {
+                writeln!(
+                    out,
+                    r#"<text x="{}" y="{}" font-family="monospace" font-size="{}" text-anchor="middle" fill="{}">{}</text>"#,
+                    x * size + size / 2,
+                    (y + 1) * size - size / 5,
+                    size,
+                    hex(colour(glyph)),
+                    glyph
+                )?;
+            } else {
+                writeln!(
+                    out,
+                    r#"<rect x="{}" y="{}" width="{2}" height="{2}" fill="{3}"/>"#,
+                    x * size,
+                    y * size,
+                    size,
+                    hex(colour(glyph))
+                )?;
+            }
+        }
+
+        writeln!(out, "</svg>")
+    }
+}
@@ -0,0 +1,52 @@
+use super::{colour, Backend, Frame};
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+/// Plays the frames in an ANSI terminal, redrawing in place with `delay` between frames.
+#[derive(Debug, Clone, Copy)]
+pub struct Terminal {
+    pub delay: Duration,
+    pub colour: bool,
+}
+
+impl Default for Terminal {
+    fn default() -> Self {
+        Terminal {
+            delay: Duration::from_millis(50),
+            colour: true,
+        }
+    }
+}
+
+impl Backend for Terminal {
+    fn render(&self, frames: &[Frame], out: &mut dyn Write) -> io::Result<()> {
+        for (i, frame) in frames.iter().enumerate() {
+            if i > 0 {
+                thread::sleep(self.delay);
+            }
+
+            // Clear the screen and home the cursor.
+            write!(out, "\x1b[2J\x1b[H")?;
+            let mut current = None;
+            for row in frame.flatten().rows() {
+                for &glyph in row {
+                    let rgb = colour(glyph);
+                    if self.colour && current != Some(rgb) {
+                        let [r, g, b] = rgb;
+                        write!(out, "\x1b[38;2;{};{};{}m", r, g, b)?;
+                        current = Some(rgb);
+                    }
+                    write!(out, "{}", glyph)?;
+                }
+                writeln!(out)?;
+            }
+            if self.colour {
+                write!(out, "\x1b[0m")?;
+            }
+            out.flush()?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,366 @@
+//! Day 1: Calorie Counting. Each elf's snacks, blank lines between elves.
+
+use crate::answer::Answer;
+use crate::error::{ParseError, ParseErrorKind, SolveError, StreamError};
+use crate::parse::{blocks, lines, parse_all, uint, Input, Parser};
+use crate::solution::Solution;
+use crate::stream::for_each_line;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap};
+use std::fmt;
+use std::io::BufRead;
+
+const DAY: u8 = 1;
+const OVERFLOW: &str = "the calories do not fit in a u64";
+
+pub mod rebalance;
+
+/// One elf's snacks, one number per line. A blank line here means two in a row.
+fn elf(input: &mut Input) -> Result<Vec<u32>, ParseError> {
+    if input.is_empty() || input.rest().starts_with('\n') {
+        return Err(input.unexpected("a number of calories"));
+    }
+
+    lines(uint()).parse(input)
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    parse_all(DAY, input, blocks(elf))
+}
+
+/// An elf's position in the input and the total it was ranked by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ranked<T> {
+    pub index: usize,
+    pub total: T,
+}
+
+/// The calories one elf carries, or `None` if they overflow.
+pub fn total(elf: &[u32]) -> Option<u64> {
+    elf.iter()
+        .try_fold(0u64, |sum, &calories| sum.checked_add(u64::from(calories)))
+}
+
+/// The calories each elf carries, in input order, or `None` if any elf's overflow.
+pub fn totals(elves: &[Vec<u32>]) -> Option<Vec<u64>> {
+    elves.iter().map(|elf| total(elf)).collect()
+}
+
+/// The `k` largest totals, largest first, with ties going to the earlier elf.
+///
+/// Keeps a heap of at most `k` entries, so it takes O(n log k) time and O(k) space.
+pub fn top_k<T: Ord>(totals: impl IntoIterator<Item = T>, k: usize) -> Vec<Ranked<T>> {
+    if k == 0 {
+        return Vec::new();
+    }
+
+    // A min-heap of what is kept so far: the root is the first to be displaced.
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for (index, total) in totals.into_iter().enumerate() {
+        let entry = Reverse((total, Reverse(index)));
+
+        if heap.len() < k {
+            heap.push(entry);
+        } else if heap.peek().is_some_and(|smallest| entry < *smallest) {
+            heap.pop();
+            heap.push(entry);
+        }
+    }
+
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((total, Reverse(index)))| Ranked { index, total })
+        .collect()
+}
+
+/// The calories carried by the `k` elves carrying the most.
+fn top_k_calories(elves: &[Vec<u32>], k: usize) -> Result<u64, SolveError> {
+    let totals = totals(elves).ok_or(SolveError::new(DAY, OVERFLOW))?;
+
+    top_k(totals, k)
+        .iter()
+        .try_fold(0u64, |sum, elf| sum.checked_add(elf.total))
+        .ok_or(SolveError::new(DAY, OVERFLOW))
+}
+
+fn part1(elves: &[Vec<u32>]) -> Result<u64, SolveError> {
+    top_k_calories(elves, 1)
+}
+
+fn part2(elves: &[Vec<u32>]) -> Result<u64, SolveError> {
+    top_k_calories(elves, 3)
+}
+
+/// One elf in an [`Inventory`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElfSummary {
+    /// Where the elf comes in the input, from 0.
+    pub index: usize,
+    pub items: usize,
+    pub total: u64,
+}
+
+/// Statistics over every elf's calories, for looking at an inventory rather than solving it.
+#[derive(Debug, Clone)]
+pub struct Inventory {
+    elves: Vec<ElfSummary>,
+    sorted: Vec<u64>,
+}
+
+impl Inventory {
+    pub fn new(elves: &[Vec<u32>]) -> Self {
+        let elves = elves
+            .iter()
+            .enumerate()
+            .map(|(index, elf)| ElfSummary {
+                index,
+                items: elf.len(),
+                total: total(elf).unwrap_or(u64::MAX),
+            })
+            .collect::<Vec<_>>();
+        let mut sorted = elves.iter().map(|elf| elf.total).collect::<Vec<_>>();
+        sorted.sort_unstable();
+
+        Inventory { elves, sorted }
+    }
+
+    /// Every elf, in input order.
+    pub fn elves(&self) -> &[ElfSummary] {
+        &self.elves
+    }
+
+    /// The `k` elves carrying the most, most first.
+    pub fn top(&self, k: usize) -> Vec<ElfSummary> {
+        top_k(self.elves.iter().map(|elf| elf.total), k)
+            .into_iter()
+            .map(|ranked| self.elves[ranked.index])
+            .collect()
+    }
+
+    /// Every elf's calories together, stopping at `u64::MAX` rather than overflowing.
+    pub fn total(&self) -> u64 {
+        self.sorted
+            .iter()
+            .fold(0, |sum, &total| sum.saturating_add(total))
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        if self.sorted.is_empty() {
+            None
+        } else {
+            Some(self.total() as f64 / self.sorted.len() as f64)
+        }
+    }
+
+    pub fn median(&self) -> Option<f64> {
+        self.percentile(50.0)
+    }
+
+    /// The `p`th percentile of the totals, from 0 to 100, interpolating linearly between the
+    /// two nearest elves.
+    pub fn percentile(&self, p: f64) -> Option<f64> {
+        let last = self.sorted.len().checked_sub(1)?;
+        let rank = p.clamp(0.0, 100.0) / 100.0 * last as f64;
+        let (below, above) = (rank.floor() as usize, rank.ceil() as usize);
+        let fraction = rank - below as f64;
+
+        Some(self.sorted[below] as f64 * (1.0 - fraction) + self.sorted[above] as f64 * fraction)
+    }
+
+    /// How many elves carry each number of items.
+    pub fn item_counts(&self) -> BTreeMap<usize, usize> {
+        let mut counts = BTreeMap::new();
+        for elf in &self.elves {
+            *counts.entry(elf.items).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Elves more than 1.5 interquartile ranges outside the middle half, in input order.
+    pub fn outliers(&self) -> Vec<ElfSummary> {
+        let (Some(q1), Some(q3)) = (self.percentile(25.0), self.percentile(75.0)) else {
+            return Vec::new();
+        };
+        let fence = 1.5 * (q3 - q1);
+
+        self.elves
+            .iter()
+            .filter(|elf| {
+                let total = elf.total as f64;
+                total < q1 - fence || total > q3 + fence
+            })
+            .copied()
+            .collect()
+    }
+
+    /// How many elves fall in each of `buckets` equal ranges of totals, as the lowest total in
+    /// the range and the count.
+    pub fn histogram(&self, buckets: usize) -> Vec<(u64, usize)> {
+        let (Some(&min), Some(&max)) = (self.sorted.first(), self.sorted.last()) else {
+            return Vec::new();
+        };
+        let buckets = buckets.max(1);
+        let width = ((max - min) / buckets as u64 + 1).max(1);
+
+        let mut counts = vec![0; buckets];
+        for total in &self.sorted {
+            counts[((total - min) / width) as usize] += 1;
+        }
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| (min + i as u64 * width, count))
+            .collect()
+    }
+}
+
+/// The report shown by the `inventory` command.
+impl fmt::Display for Inventory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (Some(mean), Some(median)) = (self.mean(), self.median()) else {
+            return writeln!(f, "no elves");
+        };
+
+        writeln!(
+            f,
+            "{} elves carrying {} calories in {} items",
+            self.elves.len(),
+            self.total(),
+            self.elves.iter().map(|elf| elf.items).sum::<usize>()
+        )?;
+        writeln!(f, "mean {:.1}, median {:.1}", mean, median)?;
+        let percentiles = [10.0, 25.0, 75.0, 90.0, 99.0]
+            .iter()
+            .filter_map(|&p| Some(format!("p{} {:.1}", p, self.percentile(p)?)))
+            .collect::<Vec<_>>();
+        writeln!(f, "{}", percentiles.join(", "))?;
+
+        writeln!(f, "\nmost calories:")?;
+        for elf in self.top(3) {
+            writeln!(
+                f,
+                "  elf {:>5}: {:>8}, items: {}",
+                elf.index, elf.total, elf.items
+            )?;
+        }
+
+        writeln!(f, "\nitems per elf:")?;
+        for (items, elves) in self.item_counts() {
+            writeln!(f, "  {:>3}: {}", items, elves)?;
+        }
+
+        let outliers = self.outliers();
+        if outliers.is_empty() {
+            writeln!(f, "\noutliers: none")?;
+        } else {
+            writeln!(f, "\noutliers:")?;
+        }
+        for elf in outliers {
+            writeln!(f, "  elf {:>5}: {:>8}", elf.index, elf.total)?;
+        }
+
+        let histogram = self.histogram(10);
+        let most = histogram.iter().map(|&(_, count)| count).max().unwrap_or(0);
+        writeln!(f, "\ncalories:")?;
+        for (start, count) in histogram {
+            let bar = "#".repeat((count * 40).div_ceil(most.max(1)));
+            writeln!(f, "  {:>8} | {:<40} {}", start, bar, count)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Both answers from reading one elf at a time, keeping only the three largest totals.
+pub fn stream(reader: &mut dyn BufRead) -> Result<[Answer; 2], StreamError> {
+    let mut top = [0u64; 3];
+    let mut keep = |total: u64| {
+        if total > top[2] {
+            top[2] = total;
+            top.sort_unstable_by(|a, b| b.cmp(a));
+        }
+    };
+    let mut current: Option<u64> = None;
+    let mut elves = 0;
+
+    for_each_line(reader, |index, line| {
+        if line.is_empty() {
+            let total = current.take().ok_or_else(|| {
+                ParseError::at(
+                    DAY,
+                    line,
+                    line,
+                    ParseErrorKind::Unexpected("a number of calories"),
+                )
+                .below(index)
+            })?;
+            keep(total);
+            elves += 1;
+        } else {
+            let calories: u32 = parse_all(DAY, line, uint()).map_err(|e| e.below(index))?;
+            let total = current.unwrap_or(0).checked_add(u64::from(calories));
+            current = Some(total.ok_or(SolveError::new(DAY, OVERFLOW))?);
+        }
+
+        Ok::<_, StreamError>(())
+    })?;
+    if let Some(total) = current {
+        keep(total);
+        elves += 1;
+    }
+    if elves == 0 {
+        let kind = ParseErrorKind::UnexpectedEnd("a number of calories");
+        return Err(ParseError::at(DAY, "", "", kind).into());
+    }
+
+    let top_three = top
+        .iter()
+        .try_fold(0u64, |sum, &total| sum.checked_add(total));
+    Ok([
+        top[0].into(),
+        top_three.ok_or(SolveError::new(DAY, OVERFLOW))?.into(),
+    ])
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+
+    type Parsed = Vec<Vec<u32>>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(elves: &Self::Parsed, _: &()) -> Result<Answer, SolveError> {
+        part1(elves).map(Answer::from)
+    }
+
+    fn part2(elves: &Self::Parsed, _: &()) -> Result<Answer, SolveError> {
+        part2(elves).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn top_k_matches_sorting(totals in prop::collection::vec(0u32..20, 0..40), k in 0usize..8) {
+            let mut sorted = totals
+                .iter()
+                .enumerate()
+                .map(|(index, &total)| Ranked { index, total })
+                .collect::<Vec<_>>();
+            sorted.sort_by_key(|elf| (Reverse(elf.total), elf.index));
+            sorted.truncate(k);
+
+            prop_assert_eq!(top_k(totals, k), sorted);
+        }
+    }
+}
@@ -0,0 +1,335 @@
+//! Sharing the elves' snacks out again as evenly as possible.
+
+use super::{total, DAY, OVERFLOW};
+use crate::error::SolveError;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::str::FromStr;
+
+/// How [`rebalance`] shares the snacks out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    /// Branch and bound over every assignment. Only practical for a dozen or so snacks.
+    Exact,
+    /// Longest processing time first: biggest snack to the least loaded elf.
+    Lpt,
+    /// Karmarkar-Karp's largest differencing, generalised to any number of elves.
+    KarmarkarKarp,
+}
+
+impl Method {
+    /// The most snacks `Exact` is picked for by [`Method::for_snacks`].
+    pub const EXACT_LIMIT: usize = 16;
+
+    /// `Exact` when there are few enough snacks to search, otherwise `KarmarkarKarp`.
+    pub fn for_snacks(count: usize) -> Self {
+        if count <= Self::EXACT_LIMIT {
+            Method::Exact
+        } else {
+            Method::KarmarkarKarp
+        }
+    }
+}
+
+impl FromStr for Method {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "exact" => Ok(Method::Exact),
+            "lpt" => Ok(Method::Lpt),
+            "kk" => Ok(Method::KarmarkarKarp),
+            _ => Err(format!("no such method: {}", s)),
+        }
+    }
+}
+
+/// The snacks shared out again so the most any elf carries is as small as it can be made.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rebalance {
+    method: Method,
+    elves: Vec<Vec<u32>>,
+    lower_bound: u64,
+}
+
+impl Rebalance {
+    pub fn method(&self) -> Method {
+        self.method
+    }
+
+    /// The snacks each elf ends up with.
+    pub fn elves(&self) -> &[Vec<u32>] {
+        &self.elves
+    }
+
+    /// No sharing can do better: the mean load rounded up, or the biggest snack.
+    pub fn lower_bound(&self) -> u64 {
+        self.lower_bound
+    }
+
+    pub fn loads(&self) -> impl Iterator<Item = u64> + '_ {
+        // `rebalance` checked that all the snacks together fit, so each elf's do.
+        self.elves
+            .iter()
+            .map(|elf| total(elf).expect("loads checked when rebalanced"))
+    }
+
+    pub fn max_load(&self) -> u64 {
+        self.loads().max().unwrap_or(0)
+    }
+
+    /// How far `max_load` is above `lower_bound`. An exact solution can still have a gap when
+    /// the bound cannot be met.
+    pub fn gap(&self) -> u64 {
+        self.max_load() - self.lower_bound
+    }
+}
+
+impl fmt::Display for Rebalance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "method: {:?}", self.method)?;
+        writeln!(
+            f,
+            "most carried: {}, lower bound: {}, gap: {}",
+            self.max_load(),
+            self.lower_bound,
+            self.gap()
+        )?;
+        for (index, (elf, load)) in self.elves.iter().zip(self.loads()).enumerate() {
+            let mut snacks = elf.iter().take(8).map(u32::to_string).collect::<Vec<_>>();
+            if elf.len() > snacks.len() {
+                snacks.push(format!("{} more", elf.len() - snacks.len()));
+            }
+            writeln!(
+                f,
+                "  elf {:>5}: {:>8} = {}",
+                index,
+                load,
+                snacks.join(" + ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Shares every snack out among `count` elves to minimise the most any of them carries: the
+/// multiway number partitioning problem. Fails if there are no elves to share among, or the
+/// snacks' calories together overflow.
+pub fn rebalance(
+    elves: &[Vec<u32>],
+    count: usize,
+    method: Method,
+) -> Result<Rebalance, SolveError> {
+    if count == 0 {
+        return Err(SolveError::new(DAY, "no elves to share the snacks among"));
+    }
+
+    let mut snacks = elves.iter().flatten().copied().collect::<Vec<_>>();
+    snacks.sort_unstable_by(|a, b| b.cmp(a));
+
+    let total = total(&snacks).ok_or(SolveError::new(DAY, OVERFLOW))?;
+    let lower_bound = total
+        .div_ceil(count as u64)
+        .max(snacks.first().map_or(0, |&c| u64::from(c)));
+
+    let elves = match method {
+        Method::Exact => share(&snacks, &exact(&snacks, count, lower_bound), count),
+        Method::Lpt => share(&snacks, &lpt(&snacks, count), count),
+        Method::KarmarkarKarp => karmarkar_karp(&snacks, count),
+    };
+
+    Ok(Rebalance {
+        method,
+        elves,
+        lower_bound,
+    })
+}
+
+/// Each snack, biggest first, to whichever elf carries least so far. Returns the elf each
+/// snack went to.
+fn lpt(snacks: &[u32], count: usize) -> Vec<usize> {
+    let mut loads = (0..count)
+        .map(|elf| Reverse((0u64, elf)))
+        .collect::<BinaryHeap<_>>();
+
+    snacks
+        .iter()
+        .map(|&snack| {
+            let Reverse((load, elf)) = loads.pop().unwrap();
+            loads.push(Reverse((load + u64::from(snack), elf)));
+            elf
+        })
+        .collect()
+}
+
+/// Starts with each snack as a partition of its own and repeatedly merges the two partitions
+/// whose loads are furthest apart, pairing one's heaviest elf with the other's lightest.
+fn karmarkar_karp(snacks: &[u32], count: usize) -> Vec<Vec<u32>> {
+    // Each partition's elves, heaviest first.
+    type Partition = Vec<(u64, Vec<u32>)>;
+    let spread = |partition: &Partition| partition[0].0 - partition[count - 1].0;
+
+    let mut partitions = Vec::new();
+    let mut by_spread = BinaryHeap::new();
+    for &snack in snacks {
+        let mut partition = vec![(0, Vec::new()); count];
+        partition[0] = (u64::from(snack), vec![snack]);
+
+        by_spread.push((spread(&partition), partitions.len()));
+        partitions.push(Some(partition));
+    }
+
+    while by_spread.len() > 1 {
+        let (_, a) = by_spread.pop().unwrap();
+        let (_, b) = by_spread.pop().unwrap();
+        let a = partitions[a].take().unwrap();
+        let b = partitions[b].take().unwrap();
+
+        let mut merged = a
+            .into_iter()
+            .zip(b.into_iter().rev())
+            .map(|((load_a, mut a), (load_b, b))| {
+                a.extend(b);
+                (load_a + load_b, a)
+            })
+            .collect::<Partition>();
+        merged.sort_by_key(|elf| Reverse(elf.0));
+
+        by_spread.push((spread(&merged), partitions.len()));
+        partitions.push(Some(merged));
+    }
+
+    match by_spread.pop() {
+        Some((_, last)) => partitions[last]
+            .take()
+            .unwrap()
+            .into_iter()
+            .map(|(_, elf)| elf)
+            .collect(),
+        None => vec![Vec::new(); count],
+    }
+}
+
+/// Tries every elf for each snack, biggest first, abandoning any branch that cannot beat the
+/// best found so far. Starts from LPT's answer and stops early if it meets `lower_bound`.
+fn exact(snacks: &[u32], count: usize, lower_bound: u64) -> Vec<usize> {
+    struct Search<'a> {
+        snacks: &'a [u32],
+        lower_bound: u64,
+        loads: Vec<u64>,
+        assigned: Vec<usize>,
+        best: u64,
+        best_assigned: Vec<usize>,
+    }
+
+    impl Search<'_> {
+        fn go(&mut self, next: usize) {
+            let Some(&snack) = self.snacks.get(next) else {
+                let most = self.loads.iter().copied().max().unwrap_or(0);
+                if most < self.best {
+                    self.best = most;
+                    self.best_assigned.clone_from(&self.assigned);
+                }
+                return;
+            };
+
+            let mut tried = Vec::new();
+            for elf in 0..self.loads.len() {
+                let load = self.loads[elf] + u64::from(snack);
+                // Elves carrying the same load are interchangeable, so only try one of them.
+                if load >= self.best || tried.contains(&self.loads[elf]) {
+                    continue;
+                }
+                tried.push(self.loads[elf]);
+
+                self.loads[elf] = load;
+                self.assigned[next] = elf;
+                self.go(next + 1);
+                self.loads[elf] -= u64::from(snack);
+
+                if self.best <= self.lower_bound {
+                    return;
+                }
+            }
+        }
+    }
+
+    let best_assigned = lpt(snacks, count);
+    let mut loads = vec![0; count];
+    for (&snack, &elf) in snacks.iter().zip(&best_assigned) {
+        loads[elf] += u64::from(snack);
+    }
+    let best = loads.into_iter().max().unwrap_or(0);
+
+    let mut search = Search {
+        snacks,
+        lower_bound,
+        loads: vec![0; count],
+        assigned: vec![0; snacks.len()],
+        best,
+        best_assigned,
+    };
+    if search.best > lower_bound {
+        search.go(0);
+    }
+    search.best_assigned
+}
+
+/// Each snack given to the elf `assigned` to it.
+fn share(snacks: &[u32], assigned: &[usize], count: usize) -> Vec<Vec<u32>> {
+    let mut elves = vec![Vec::new(); count];
+    for (&snack, &elf) in snacks.iter().zip(assigned) {
+        elves[elf].push(snack);
+    }
+    elves
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn exact_rebalance_matches_trying_everything(
+            snacks in prop::collection::vec(1u32..50, 0..8),
+            count in 1usize..4,
+        ) {
+            // Every way of giving each snack to an elf, counted in base `count`.
+            let best = (0..count.pow(snacks.len() as u32))
+                .map(|mut n| {
+                    let mut loads = vec![0u64; count];
+                    for &snack in &snacks {
+                        loads[n % count] += u64::from(snack);
+                        n /= count;
+                    }
+                    loads.into_iter().max().unwrap()
+                })
+                .min()
+                .unwrap();
+
+            let rebalance = rebalance(&[snacks], count, Method::Exact).unwrap();
+            prop_assert_eq!(rebalance.max_load(), best);
+        }
+
+        #[test]
+        fn rebalancing_keeps_every_snack(
+            elves in prop::collection::vec(prop::collection::vec(1u32..1000, 1..4), 1..6),
+            count in 1usize..6,
+        ) {
+            let mut snacks = elves.concat();
+            snacks.sort_unstable();
+
+            for method in [Method::Exact, Method::Lpt, Method::KarmarkarKarp] {
+                let rebalance = rebalance(&elves, count, method).unwrap();
+                let mut shared = rebalance.elves().concat();
+                shared.sort_unstable();
+
+                prop_assert_eq!(rebalance.elves().len(), count);
+                prop_assert_eq!(&shared, &snacks);
+                prop_assert!(rebalance.max_load() >= rebalance.lower_bound());
+            }
+        }
+    }
+}
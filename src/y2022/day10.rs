@@ -0,0 +1,301 @@
+//! Day 10: Cathode-Ray Tube. A CPU program that also drives a CRT.
+
+use crate::answer::Answer;
+use crate::error::{ParseError, SolutionError, SolveError, StreamError};
+use crate::geom::Point2;
+use crate::grid::Grid;
+use crate::params::{self, ParamError, Params};
+use crate::parse::{int, lines, parse_all, Input, Parser};
+use crate::solution::Solution;
+use crate::stream::for_each_parsed;
+use crate::viz::Frame;
+use std::any::Any;
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
+const DAY: u8 = 10;
+const OVERFLOW: &str = "the X register or a signal strength does not fit in an isize";
+
+/// The cycles whose signal strengths part 1 adds up.
+pub const SIGNAL_CYCLES: [usize; 6] = [20, 60, 100, 140, 180, 220];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Instr {
+    AddX(isize),
+    Noop,
+}
+use Instr::*;
+
+impl fmt::Display for Instr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AddX(v) => write!(f, "addx {}", v),
+            Noop => write!(f, "noop"),
+        }
+    }
+}
+
+fn instr(input: &mut Input) -> Result<Instr, ParseError> {
+    if input.eat("noop") {
+        Ok(Noop)
+    } else if input.eat("addx ") {
+        Ok(AddX(int().parse(input)?))
+    } else {
+        Err(input.unexpected("addx <n> or noop"))
+    }
+}
+
+impl FromStr for Instr {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_all(DAY, s, instr)
+    }
+}
+
+/// A program with the CPU running it and the 40 by 6 CRT it draws on.
+#[derive(Debug, Clone)]
+pub struct Program {
+    instructions: Vec<Instr>,
+    pc: usize,
+    cycle: usize,
+    x: isize,
+    display: Grid<bool>,
+}
+
+impl Program {
+    /// A program about to start, with X at 1 and a dark screen.
+    pub fn new(instructions: Vec<Instr>) -> Self {
+        Program {
+            instructions,
+            pc: 0,
+            cycle: 0,
+            x: 1,
+            display: Grid::new(40, 6, false),
+        }
+    }
+
+    fn draw_pixel(&mut self) {
+        let pos = self.cycle - 1;
+        let width = self.display.width();
+        let (col, row) = (pos % width, pos / width);
+
+        if let Some(pixel) = self.display.get_mut(col, row) {
+            *pixel = (col as isize).abs_diff(self.x) <= 1;
+        }
+    }
+
+    pub fn instructions(&self) -> &[Instr] {
+        &self.instructions
+    }
+
+    /// The number of cycles completed so far.
+    pub fn cycle(&self) -> usize {
+        self.cycle
+    }
+
+    /// The X register.
+    pub fn x(&self) -> isize {
+        self.x
+    }
+
+    pub fn display(&self) -> &Grid<bool> {
+        &self.display
+    }
+
+    /// Runs the program to the end, returning the total signal strength during `cycles`.
+    pub fn run_calculating_signal_strengths(
+        &mut self,
+        cycles: &[usize],
+    ) -> Result<isize, SolveError> {
+        self.run(cycles, |_| {})
+    }
+
+    /// Runs the program to the end, calling `on_cycle` once each cycle's pixel is drawn.
+    pub fn run(
+        &mut self,
+        cycles: &[usize],
+        mut on_cycle: impl FnMut(&Program),
+    ) -> Result<isize, SolveError> {
+        let mut strength = 0isize;
+
+        while self.pc < self.instructions.len() {
+            let instr = self.instructions[self.pc].clone();
+            let during = self.execute(&instr, cycles, &mut on_cycle)?;
+            strength = add(strength, during)?;
+            self.pc += 1;
+        }
+
+        Ok(strength)
+    }
+
+    /// Executes one instruction, drawing a pixel each cycle it takes, and returns the signal
+    /// strength during any of `cycles` it covers.
+    pub fn execute(
+        &mut self,
+        instr: &Instr,
+        cycles: &[usize],
+        on_cycle: &mut impl FnMut(&Program),
+    ) -> Result<isize, SolveError> {
+        let (duration, dx) = match instr {
+            Noop => (1, 0),
+            AddX(v) => (2, *v),
+        };
+        let mut strength = 0isize;
+
+        for _ in 0..duration {
+            self.cycle += 1;
+            if cycles.contains(&self.cycle) {
+                let during = isize::try_from(self.cycle)
+                    .ok()
+                    .and_then(|cycle| self.x.checked_mul(cycle))
+                    .ok_or(SolveError::new(DAY, OVERFLOW))?;
+                strength = add(strength, during)?;
+            }
+
+            self.draw_pixel();
+            on_cycle(self);
+        }
+        self.x = add(self.x, dx)?;
+
+        Ok(strength)
+    }
+
+    pub fn render_display(&self) -> Grid<char> {
+        self.display.map(|&lit| if lit { '#' } else { '.' })
+    }
+
+    /// The CRT so far, with the sprite's three pixels over the row being drawn (the first row
+    /// before any cycle has run).
+    pub fn frame(&self) -> Frame {
+        let row = (self.cycle.saturating_sub(1) / self.display.width()) as isize;
+
+        (self.x.saturating_sub(1)..=self.x.saturating_add(1))
+            .fold(Frame::new(self.render_display()), |frame, x| {
+                frame.with_sprite(Point2::new(x, row), '=')
+            })
+    }
+
+    pub fn into_display(self) -> Grid<bool> {
+        self.display
+    }
+}
+
+fn add(a: isize, b: isize) -> Result<isize, SolveError> {
+    a.checked_add(b).ok_or(SolveError::new(DAY, OVERFLOW))
+}
+
+fn parse_input(input: &str) -> Result<Program, ParseError> {
+    Ok(Program::new(parse_all(DAY, input, lines(instr))?))
+}
+
+pub fn visualize(input: &str) -> Result<Vec<Frame>, SolutionError> {
+    let mut program = parse_input(input)?;
+    let mut frames = Vec::new();
+
+    program.run(&[], |program| frames.push(program.frame()))?;
+    frames.push(Frame::new(program.render_display()));
+
+    Ok(frames)
+}
+
+/// The cycles part 1 samples.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day10Params {
+    pub cycles: Vec<usize>,
+}
+
+impl Default for Day10Params {
+    fn default() -> Self {
+        Day10Params {
+            cycles: SIGNAL_CYCLES.to_vec(),
+        }
+    }
+}
+
+impl Params for Day10Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), ParamError> {
+        match key {
+            "cycles" => self.cycles = params::list(key, value)?,
+            _ => return Err(ParamError::UnknownKey(key.to_string())),
+        }
+        Ok(())
+    }
+
+    fn values(&self) -> Vec<(&'static str, Option<String>)> {
+        vec![("cycles", Some(params::toml_list(&self.cycles)))]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn part1(program: &Program, params: &Day10Params) -> Result<isize, SolveError> {
+    let mut program = program.clone();
+
+    program.run_calculating_signal_strengths(&params.cycles)
+}
+
+fn part2(program: &Program) -> Result<Grid<bool>, SolveError> {
+    let mut program = program.clone();
+    let cycles = [];
+
+    program.run_calculating_signal_strengths(&cycles[..])?;
+
+    Ok(program.into_display())
+}
+
+/// Both answers, executing each instruction as it is read.
+pub fn stream(reader: &mut dyn BufRead, params: &Day10Params) -> Result<[Answer; 2], StreamError> {
+    let mut program = Program::new(Vec::new());
+    let mut strength = 0;
+
+    for_each_parsed(reader, |instr: Instr| {
+        let during = program.execute(&instr, &params.cycles, &mut |_| {})?;
+        strength = add(strength, during)?;
+        Ok(())
+    })?;
+
+    Ok([strength.into(), program.into_display().into()])
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+
+    type Parsed = Program;
+    type Params = Day10Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(program: &Self::Parsed, params: &Day10Params) -> Result<Answer, SolveError> {
+        Ok(part1(program, params)?.into())
+    }
+
+    fn part2(program: &Self::Parsed, _: &Day10Params) -> Result<Answer, SolveError> {
+        Ok(part2(program)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn instr() -> impl Strategy<Value = Instr> {
+        prop_oneof![Just(Noop), any::<isize>().prop_map(AddX)]
+    }
+
+    proptest! {
+        #[test]
+        fn instr_round_trips(instr in instr()) {
+            prop_assert_eq!(instr.to_string().parse::<Instr>()?, instr);
+        }
+    }
+}
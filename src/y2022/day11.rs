@@ -0,0 +1,376 @@
+//! Day 11: Monkey in the Middle. Monkeys passing items by how worrying they are.
+
+use crate::answer::Answer;
+use crate::error::{ParseError, ParseErrorKind, SolveError};
+use crate::params::{self, ParamError, Params};
+use crate::parse::{
+    blocks, line, literal, parse_all, separated, spanned, try_map, uint, Input, Parser, Spanned,
+};
+use crate::solution::Solution;
+use itertools::Itertools;
+use std::any::Any;
+use std::num::NonZeroU128;
+
+const DAY: u8 = 11;
+const OVERFLOW: &str = "a worry level does not fit in a u128";
+
+/// How a monkey's inspection changes an item's worry level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add(u128),
+    Mul(u128),
+    Square,
+}
+
+impl Operation {
+    /// The new worry level, or `None` if it overflows.
+    pub fn apply(self, old: u128) -> Option<u128> {
+        match self {
+            Operation::Add(n) => old.checked_add(n),
+            Operation::Mul(n) => old.checked_mul(n),
+            Operation::Square => old.checked_mul(old),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<u128>,
+    operation: Operation,
+    divisor: u128,
+    true_monkey: usize,
+    false_monkey: usize,
+    inspected_item_count: u64,
+}
+
+impl Monkey {
+    /// A monkey holding `items`, throwing to `true_monkey` when a worry level is divisible
+    /// by `divisor` and to `false_monkey` otherwise.
+    pub fn new(
+        items: Vec<u128>,
+        operation: Operation,
+        divisor: u128,
+        true_monkey: usize,
+        false_monkey: usize,
+    ) -> Self {
+        Monkey {
+            items,
+            operation,
+            divisor,
+            true_monkey,
+            false_monkey,
+            inspected_item_count: 0,
+        }
+    }
+
+    /// Worry levels of the items held, next to be inspected first.
+    pub fn items(&self) -> &[u128] {
+        &self.items
+    }
+
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    pub fn divisor(&self) -> u128 {
+        self.divisor
+    }
+
+    /// The monkeys thrown to when the test passes and when it fails.
+    pub fn targets(&self) -> (usize, usize) {
+        (self.true_monkey, self.false_monkey)
+    }
+
+    pub fn inspected_item_count(&self) -> u64 {
+        self.inspected_item_count
+    }
+
+    /// Inspects the next item, returning its new worry level and the monkey it goes to, or
+    /// `None` if there are no items left. Without relief, worry levels are kept modulo `base`
+    /// if there is one; with it, they are kept exactly, and overflowing is an error.
+    pub fn inspect_and_throw(
+        &mut self,
+        with_relief: bool,
+        base: Option<NonZeroU128>,
+    ) -> Result<Option<(u128, usize)>, SolveError> {
+        if self.items.is_empty() {
+            return Ok(None);
+        }
+
+        let inspected_item = self.items.remove(0);
+        // worry level
+        let inspected_item = self
+            .operation
+            .apply(inspected_item)
+            .ok_or(SolveError::new(DAY, OVERFLOW))?;
+        // got bored
+        let inspected_item = match (with_relief, base) {
+            (true, _) => inspected_item / 3,
+            (false, Some(base)) => inspected_item % base,
+            (false, None) => inspected_item,
+        };
+
+        self.inspected_item_count += 1;
+
+        if inspected_item.is_multiple_of(self.divisor) {
+            Ok(Some((inspected_item, self.true_monkey)))
+        } else {
+            Ok(Some((inspected_item, self.false_monkey)))
+        }
+    }
+}
+
+/// Monkeys playing keep-away, worry levels kept small enough to play for as long as needed.
+pub struct MonkeyGroup {
+    monkeys: Vec<Monkey>,
+    base: Option<NonZeroU128>,
+}
+
+impl MonkeyGroup {
+    pub fn new(monkeys: Vec<Monkey>) -> Self {
+        // The tests only look at worry levels modulo their divisors, so without relief worry
+        // levels can be kept modulo the divisors' product, if it fits and none of them is 0.
+        let base = monkeys
+            .iter()
+            .try_fold(1u128, |base, m| base.checked_mul(m.divisor))
+            .and_then(NonZeroU128::new);
+
+        MonkeyGroup { monkeys, base }
+    }
+
+    /// Monkeys keeping worry levels modulo `base` instead of one worked out from their
+    /// divisors. Answers are only right if `base` is a multiple of every divisor.
+    pub fn with_base(monkeys: Vec<Monkey>, base: NonZeroU128) -> Self {
+        MonkeyGroup {
+            monkeys,
+            base: Some(base),
+        }
+    }
+
+    pub fn monkeys(&self) -> &[Monkey] {
+        &self.monkeys
+    }
+
+    /// What worry levels are kept modulo when there is no relief, or `None` if the divisors'
+    /// product overflows or is 0.
+    pub fn base(&self) -> Option<NonZeroU128> {
+        self.base
+    }
+
+    /// Lets every monkey take its turn, dividing worry levels by 3 after each inspection if
+    /// `with_relief`. Fails if a worry level overflows.
+    pub fn play_round(&mut self, with_relief: bool) -> Result<(), SolveError> {
+        for i in 0..self.monkeys.len() {
+            while let Some((item, monkey_num)) =
+                self.monkeys[i].inspect_and_throw(with_relief, self.base)?
+            {
+                self.monkeys[monkey_num].items.push(item);
+            }
+        }
+        Ok(())
+    }
+
+    /// The product of the two largest numbers of items inspected, or `None` if it overflows.
+    pub fn monkey_business(&self) -> Option<u64> {
+        self.monkeys
+            .iter()
+            .map(|m| m.inspected_item_count)
+            .sorted()
+            .rev()
+            .take(2)
+            .try_fold(1u64, u64::checked_mul)
+    }
+}
+
+fn operation(input: &mut Input) -> Result<Operation, ParseError> {
+    literal("new = old ").parse(input)?;
+
+    if input.eat("* old") {
+        Ok(Operation::Square)
+    } else if input.eat("* ") {
+        Ok(Operation::Mul(uint().parse(input)?))
+    } else if input.eat("+ ") {
+        Ok(Operation::Add(uint().parse(input)?))
+    } else {
+        Err(input.unexpected("+ or * followed by a number or old"))
+    }
+}
+
+/// One monkey's notes, with the spans of the monkey numbers so they can be checked once every
+/// monkey is known.
+struct Notes {
+    number: Spanned<usize>,
+    monkey: Monkey,
+    targets: [Spanned<usize>; 2],
+}
+
+fn notes(input: &mut Input) -> Result<Notes, ParseError> {
+    let number = line(|input: &mut Input| {
+        literal("Monkey ").parse(input)?;
+        let number = spanned(uint()).parse(input)?;
+        literal(":").parse(input)?;
+        Ok(number)
+    })
+    .parse(input)?;
+    let items = line(|input: &mut Input| {
+        literal("  Starting items: ").parse(input)?;
+        separated(uint(), ", ").parse(input)
+    })
+    .parse(input)?;
+    let operation = line(|input: &mut Input| {
+        literal("  Operation: ").parse(input)?;
+        operation(input)
+    })
+    .parse(input)?;
+    let divisor = line(|input: &mut Input| {
+        literal("  Test: divisible by ").parse(input)?;
+        try_map(uint(), |n| {
+            (n > 0)
+                .then_some(n)
+                .ok_or(ParseErrorKind::Unexpected("a divisor above 0"))
+        })
+        .parse(input)
+    })
+    .parse(input)?;
+    let true_monkey = line(|input: &mut Input| {
+        literal("    If true: throw to monkey ").parse(input)?;
+        spanned(uint()).parse(input)
+    })
+    .parse(input)?;
+    let false_monkey = line(|input: &mut Input| {
+        literal("    If false: throw to monkey ").parse(input)?;
+        spanned(uint()).parse(input)
+    })
+    .parse(input)?;
+
+    Ok(Notes {
+        number,
+        monkey: Monkey::new(
+            items,
+            operation,
+            divisor,
+            true_monkey.value,
+            false_monkey.value,
+        ),
+        targets: [true_monkey, false_monkey],
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    parse_all(DAY, input, |input: &mut Input| {
+        let all_notes = blocks(notes).parse(input)?;
+
+        for (i, notes) in all_notes.iter().enumerate() {
+            if notes.number.value != i {
+                return Err(input.error_at(
+                    notes.number.span,
+                    ParseErrorKind::Unexpected("monkeys numbered in order from 0"),
+                ));
+            }
+            for target in &notes.targets {
+                if target.value == i || target.value >= all_notes.len() {
+                    return Err(input.error_at(
+                        target.span,
+                        ParseErrorKind::Unexpected("another monkey's number"),
+                    ));
+                }
+            }
+        }
+
+        Ok(all_notes.into_iter().map(|notes| notes.monkey).collect())
+    })
+}
+
+/// How long each part plays for, and optionally what worry levels are kept modulo in part 2.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day11Params {
+    pub relief_rounds: usize,
+    pub rounds: usize,
+    /// Worked out from the monkeys' divisors if `None`.
+    pub base: Option<NonZeroU128>,
+}
+
+impl Default for Day11Params {
+    fn default() -> Self {
+        Day11Params {
+            relief_rounds: 20,
+            rounds: 10000,
+            base: None,
+        }
+    }
+}
+
+impl Params for Day11Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), ParamError> {
+        match key {
+            "relief_rounds" => self.relief_rounds = params::number(key, value)?,
+            "rounds" => self.rounds = params::number(key, value)?,
+            "base" => self.base = Some(params::number(key, value)?),
+            _ => return Err(ParamError::UnknownKey(key.to_string())),
+        }
+        Ok(())
+    }
+
+    fn values(&self) -> Vec<(&'static str, Option<String>)> {
+        vec![
+            ("relief_rounds", Some(self.relief_rounds.to_string())),
+            ("rounds", Some(self.rounds.to_string())),
+            ("base", self.base.map(|base| base.to_string())),
+        ]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn play(
+    monkeys: &[Monkey],
+    base: Option<NonZeroU128>,
+    rounds: usize,
+    with_relief: bool,
+) -> Result<u64, SolveError> {
+    let mut group = match base {
+        Some(base) => MonkeyGroup::with_base(monkeys.to_vec(), base),
+        None => MonkeyGroup::new(monkeys.to_vec()),
+    };
+
+    for _ in 0..rounds {
+        group.play_round(with_relief)?;
+    }
+
+    group.monkey_business().ok_or(SolveError::new(
+        DAY,
+        "the monkey business does not fit in a u64",
+    ))
+}
+
+fn part1(monkeys: &[Monkey], params: &Day11Params) -> Result<u64, SolveError> {
+    play(monkeys, params.base, params.relief_rounds, true)
+}
+
+fn part2(monkeys: &[Monkey], params: &Day11Params) -> Result<u64, SolveError> {
+    play(monkeys, params.base, params.rounds, false)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+
+    type Parsed = Vec<Monkey>;
+    type Params = Day11Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(monkeys: &Self::Parsed, params: &Day11Params) -> Result<Answer, SolveError> {
+        part1(monkeys, params).map(Answer::from)
+    }
+
+    fn part2(monkeys: &Self::Parsed, params: &Day11Params) -> Result<Answer, SolveError> {
+        part2(monkeys, params).map(Answer::from)
+    }
+}
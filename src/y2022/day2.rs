@@ -0,0 +1,460 @@
+//! Day 2: Rock Paper Scissors. A strategy guide of rounds, scored two ways under the rules of
+//! a [`Game`], which can be any hand game given as data.
+
+use crate::answer::Answer;
+use crate::error::{parse_lines, ParseError, ParseErrorKind, SolveError, StreamError};
+use crate::solution::Solution;
+use crate::stream::for_each_parsed;
+use std::cmp::Reverse;
+use std::error::Error;
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
+const DAY: u8 = 2;
+const NO_SHAPE: &str = "a round names a shape the game lacks, or an outcome no shape gets";
+
+/// A shape, by its place in a [`Game`]'s list.
+///
+/// The strategy guide only has letters for the first three: `A`, `B` and `C` for the
+/// opponent, and `X`, `Y` and `Z` for a [`Response`] read as a shape. Other shapes can be
+/// played through the [`Game`] API but never appear in a [`Round`].
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+pub struct Shape(pub usize);
+
+impl Shape {
+    /// How many shapes the strategy guide can name.
+    pub const IN_GUIDE: usize = 3;
+}
+
+impl FromStr for Shape {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" => Ok(Shape(0)),
+            "B" => Ok(Shape(1)),
+            "C" => Ok(Shape(2)),
+            _ => Err(ParseError::at(
+                DAY,
+                s,
+                s,
+                ParseErrorKind::Unexpected("one of A, B or C"),
+            )),
+        }
+    }
+}
+
+/// The guide's letter, or `#n` for a shape it has no letter for.
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            0 => f.write_str("A"),
+            1 => f.write_str("B"),
+            2 => f.write_str("C"),
+            n => write!(f, "#{}", n),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+use Outcome::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameError {
+    UnknownShape(usize),
+    /// Two shapes each said to beat the other, or a shape said to beat itself.
+    Mutual(usize, usize),
+    /// Only an odd number of shapes can each beat exactly half of the others.
+    EvenCycle(usize),
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::UnknownShape(i) => write!(f, "no shape {}", i),
+            GameError::Mutual(a, b) => write!(f, "shapes {} and {} both beat each other", a, b),
+            GameError::EvenCycle(n) => {
+                write!(f, "a cyclic game needs an odd number of shapes, not {}", n)
+            }
+        }
+    }
+}
+
+impl Error for GameError {}
+
+/// The rules of a hand game: its shapes and what each is worth, which beats which, and what
+/// losing, drawing and winning are worth. Shapes neither of which beats the other draw.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    names: Vec<String>,
+    values: Vec<u32>,
+    /// `beats[a * len + b]` if shape `a` beats shape `b`.
+    beats: Vec<bool>,
+    outcome_scores: [u32; 3],
+}
+
+impl Game {
+    /// A game of `shapes`, each a name and the score for playing it, where every `(a, b)` in
+    /// `beats` means shape `a` beats shape `b`. Scores 0, 3 and 6 for each outcome.
+    pub fn new(shapes: &[(&str, u32)], beats: &[(usize, usize)]) -> Result<Self, GameError> {
+        let len = shapes.len();
+        let mut game = Game {
+            names: shapes.iter().map(|(name, _)| name.to_string()).collect(),
+            values: shapes.iter().map(|&(_, value)| value).collect(),
+            beats: vec![false; len * len],
+            outcome_scores: [0, 3, 6],
+        };
+
+        for &(a, b) in beats {
+            if let Some(&unknown) = [a, b].iter().find(|&&i| i >= len) {
+                return Err(GameError::UnknownShape(unknown));
+            }
+            if a == b || game.beats[b * len + a] {
+                return Err(GameError::Mutual(a, b));
+            }
+            game.beats[a * len + b] = true;
+        }
+
+        Ok(game)
+    }
+
+    /// A game of an odd number of shapes in which each beats the half of the others that come
+    /// just before it in the list, wrapping around, and is worth its place in the list from 1.
+    pub fn cyclic(names: &[&str]) -> Result<Self, GameError> {
+        let len = names.len();
+        if len.is_multiple_of(2) {
+            return Err(GameError::EvenCycle(len));
+        }
+
+        let shapes = names
+            .iter()
+            .zip(1..)
+            .map(|(&name, value)| (name, value))
+            .collect::<Vec<_>>();
+        let beats = (0..len)
+            .flat_map(|a| (1..=len / 2).map(move |back| (a, (a + len - back) % len)))
+            .collect::<Vec<_>>();
+
+        Game::new(&shapes, &beats)
+    }
+
+    /// The puzzle's game.
+    pub fn rock_paper_scissors() -> Self {
+        Game::cyclic(&["Rock", "Paper", "Scissors"]).unwrap()
+    }
+
+    /// Rock, Paper, Scissors, Lizard and Spock, worth 1 to 5 in that order.
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        let shapes = [
+            ("Rock", 1),
+            ("Paper", 2),
+            ("Scissors", 3),
+            ("Lizard", 4),
+            ("Spock", 5),
+        ];
+        let beats = [
+            (0, 2),
+            (0, 3),
+            (1, 0),
+            (1, 4),
+            (2, 1),
+            (2, 3),
+            (3, 1),
+            (3, 4),
+            (4, 0),
+            (4, 2),
+        ];
+
+        Game::new(&shapes, &beats).unwrap()
+    }
+
+    pub fn with_outcome_scores(mut self, lose: u32, draw: u32, win: u32) -> Self {
+        self.outcome_scores = [lose, draw, win];
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        (0..self.len()).map(Shape)
+    }
+
+    /// Whether `shape` is one of this game's. The methods below panic on shapes that are not.
+    pub fn contains(&self, shape: Shape) -> bool {
+        shape.0 < self.len()
+    }
+
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.names[shape.0]
+    }
+
+    pub fn value(&self, shape: Shape) -> u32 {
+        self.values[shape.0]
+    }
+
+    /// How a round ends for whoever plays `mine` against `theirs`.
+    pub fn outcome(&self, mine: Shape, theirs: Shape) -> Outcome {
+        if self.beats[mine.0 * self.len() + theirs.0] {
+            Win
+        } else if self.beats[theirs.0 * self.len() + mine.0] {
+            Lose
+        } else {
+            Draw
+        }
+    }
+
+    /// The shape to play against `opponent` to get `outcome`. If several would, the one worth
+    /// most, and the first of those.
+    pub fn shape_for(&self, opponent: Shape, outcome: Outcome) -> Option<Shape> {
+        self.shapes()
+            .filter(|&mine| self.outcome(mine, opponent) == outcome)
+            .min_by_key(|&mine| Reverse(self.value(mine)))
+    }
+
+    /// My score for a round: my shape's value plus what the outcome is worth.
+    pub fn score(&self, opponent: Shape, mine: Shape) -> u32 {
+        self.value(mine) + self.outcome_scores[self.outcome(mine, opponent) as usize]
+    }
+}
+
+/// The second column of the strategy guide, which each part reads differently.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Response {
+    X,
+    Y,
+    Z,
+}
+
+impl FromStr for Response {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "X" => Ok(Response::X),
+            "Y" => Ok(Response::Y),
+            "Z" => Ok(Response::Z),
+            _ => Err(ParseError::at(
+                DAY,
+                s,
+                s,
+                ParseErrorKind::Unexpected("one of X, Y or Z"),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Response {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let column = match self {
+            Response::X => "X",
+            Response::Y => "Y",
+            Response::Z => "Z",
+        };
+
+        f.write_str(column)
+    }
+}
+
+impl Response {
+    /// The first part's reading: the shape to play, `X` being the first.
+    pub fn as_shape(&self) -> Shape {
+        Shape(*self as usize)
+    }
+
+    /// The second part's reading: how the round must end.
+    pub fn as_outcome(&self) -> Outcome {
+        match self {
+            Response::X => Lose,
+            Response::Y => Draw,
+            Response::Z => Win,
+        }
+    }
+}
+
+/// Which way to read a [`Response`].
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Reading {
+    Shape,
+    Outcome,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round(Shape, Response);
+
+impl Round {
+    /// A round of the guide, or `None` if the guide has no letter for `opponent`.
+    pub fn new(opponent: Shape, response: Response) -> Option<Self> {
+        (opponent.0 < Shape::IN_GUIDE).then_some(Round(opponent, response))
+    }
+
+    pub fn opponent(&self) -> Shape {
+        self.0
+    }
+
+    pub fn response(&self) -> Response {
+        self.1
+    }
+
+    /// The shape I play with the response read `reading`'s way, or `None` if either shape is
+    /// not in `game` or no shape in it gets the outcome asked for.
+    pub fn my_shape(&self, game: &Game, reading: Reading) -> Option<Shape> {
+        if !game.contains(self.0) {
+            return None;
+        }
+
+        match reading {
+            Reading::Shape => Some(self.1.as_shape()).filter(|&mine| game.contains(mine)),
+            Reading::Outcome => game.shape_for(self.0, self.1.as_outcome()),
+        }
+    }
+
+    pub fn score(&self, game: &Game, reading: Reading) -> Option<u32> {
+        Some(game.score(self.0, self.my_shape(game, reading)?))
+    }
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (opponent, mine) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at(DAY, s, s, ParseErrorKind::MissingDelimiter(" ")))?;
+
+        Ok(Round(
+            opponent
+                .parse()
+                .map_err(|e: ParseError| e.inside(s, opponent))?,
+            mine.parse().map_err(|e: ParseError| e.inside(s, mine))?,
+        ))
+    }
+}
+
+impl fmt::Display for Round {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.0, self.1)
+    }
+}
+
+/// Every round's score under `game`'s rules, added up.
+pub fn total_score(rounds: &[Round], game: &Game, reading: Reading) -> Result<u64, SolveError> {
+    rounds.iter().try_fold(0u64, |total, round| {
+        let score = round
+            .score(game, reading)
+            .ok_or(SolveError::new(DAY, NO_SHAPE))?;
+        Ok(total + u64::from(score))
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Round>, ParseError> {
+    parse_lines(input)
+}
+
+/// Both totals, scoring each round as it is read.
+pub fn stream(reader: &mut dyn BufRead) -> Result<[Answer; 2], StreamError> {
+    let game = Game::rock_paper_scissors();
+    let mut totals = [0u64; 2];
+
+    for_each_parsed(reader, |round: Round| {
+        for (total, reading) in totals.iter_mut().zip([Reading::Shape, Reading::Outcome]) {
+            let score = round.score(&game, reading);
+            *total += u64::from(score.ok_or(SolveError::new(DAY, NO_SHAPE))?);
+        }
+        Ok(())
+    })?;
+
+    Ok(totals.map(Answer::from))
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+
+    type Parsed = Vec<Round>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(rounds: &Self::Parsed, _: &()) -> Result<Answer, SolveError> {
+        total_score(rounds, &Game::rock_paper_scissors(), Reading::Shape).map(Answer::from)
+    }
+
+    fn part2(rounds: &Self::Parsed, _: &()) -> Result<Answer, SolveError> {
+        total_score(rounds, &Game::rock_paper_scissors(), Reading::Outcome).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn response() -> impl Strategy<Value = Response> {
+        prop_oneof![Just(Response::X), Just(Response::Y), Just(Response::Z)]
+    }
+
+    proptest! {
+        #[test]
+        fn rounds_round_trip_for_shapes_in_the_guide(shape in 0usize..8, response in response()) {
+            match Round::new(Shape(shape), response) {
+                Some(round) => prop_assert_eq!(round.to_string().parse::<Round>()?, round),
+                None => {
+                    prop_assert!(shape >= Shape::IN_GUIDE);
+                    prop_assert!(Shape(shape).to_string().parse::<Shape>().is_err());
+                }
+            }
+        }
+
+        #[test]
+        fn rounds_score_only_with_shapes_in_the_game(
+            len in 1usize..4,
+            shape in 0usize..3,
+            response in response(),
+        ) {
+            let shapes = (1..=len as u32).map(|value| ("shape", value)).collect::<Vec<_>>();
+            let game = Game::new(&shapes, &[]).unwrap();
+            let round = Round::new(Shape(shape), response).unwrap();
+
+            for reading in [Reading::Shape, Reading::Outcome] {
+                match round.my_shape(&game, reading) {
+                    Some(mine) => {
+                        prop_assert!(game.contains(mine));
+                        prop_assert!(round.score(&game, reading).is_some());
+                    }
+                    None => prop_assert_eq!(round.score(&game, reading), None),
+                }
+            }
+            prop_assert_eq!(round.score(&game, Reading::Shape).is_some(), shape < len && (response as usize) < len);
+        }
+
+        #[test]
+        fn cyclic_games_are_balanced(half in 0usize..6, a in 0usize..13, b in 0usize..13) {
+            let names = vec!["shape"; 2 * half + 1];
+            let game = Game::cyclic(&names).unwrap();
+            let (a, b) = (Shape(a % game.len()), Shape(b % game.len()));
+
+            let wins = game.shapes().filter(|&other| game.outcome(a, other) == Win).count();
+            prop_assert_eq!(wins, half);
+
+            let outcome = game.outcome(a, b);
+            prop_assert_eq!(game.outcome(b, a), [Win, Draw, Lose][outcome as usize]);
+            prop_assert_eq!(game.outcome(game.shape_for(b, outcome).unwrap(), b), outcome);
+        }
+    }
+}
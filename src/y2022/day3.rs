@@ -0,0 +1,172 @@
+//! Day 3: Rucksack Reorganization. Rucksacks of two compartments, in groups of three.
+
+use crate::answer::Answer;
+use crate::error::{parse_lines, ParseError, ParseErrorKind, SolveError, StreamError};
+use crate::solution::Solution;
+use crate::stream::for_each_parsed;
+use std::collections::HashSet;
+use std::io::BufRead;
+use std::str::FromStr;
+
+const DAY: u8 = 3;
+
+/// An item's priority: 1 to 26 for `a` to `z`, 27 to 52 for `A` to `Z`.
+pub fn elem_priority(c: &char) -> u32 {
+    if c.is_ascii_lowercase() {
+        *c as u32 - 96
+    } else {
+        *c as u32 - 64 + 26
+    }
+}
+
+fn check_items(s: &str) -> Result<(), ParseError> {
+    match s.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+        Some((i, c)) => Err(ParseError::at(
+            DAY,
+            s,
+            &s[i..i + c.len_utf8()],
+            ParseErrorKind::Unexpected("an item letter a-z or A-Z"),
+        )),
+        None => Ok(()),
+    }
+}
+
+#[derive(Debug, Clone)]
+/// A rucksack's items, split into its two compartments.
+pub struct Rucksack(HashSet<char>, HashSet<char>);
+
+impl FromStr for Rucksack {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        check_items(s)?;
+
+        if !s.len().is_multiple_of(2) {
+            return Err(ParseError::at(
+                DAY,
+                s,
+                s,
+                ParseErrorKind::Unexpected("an even number of items"),
+            ));
+        }
+
+        let (s1, s2) = s.split_at(s.len() / 2);
+
+        Ok(Rucksack(s1.chars().collect(), s2.chars().collect()))
+    }
+}
+
+impl Rucksack {
+    pub fn compartments(&self) -> (&HashSet<char>, &HashSet<char>) {
+        (&self.0, &self.1)
+    }
+
+    /// The priority of an item in both compartments, if there is one.
+    pub fn priority_shared_elem(&self) -> Option<u32> {
+        let Rucksack(l, r) = self;
+
+        let shared = l.intersection(r).next()?;
+
+        Some(elem_priority(shared))
+    }
+
+    /// Every item in either compartment.
+    pub fn items(&self) -> HashSet<char> {
+        let Rucksack(l, r) = self;
+
+        l.union(r).cloned().collect()
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+    parse_lines(input)
+}
+
+fn part1(rucksacks: &[Rucksack]) -> u32 {
+    rucksacks
+        .iter()
+        .map(|r| r.priority_shared_elem().unwrap_or(0))
+        .sum()
+}
+
+/// The items carried by each of a group of three elves.
+pub struct ElfGroup(HashSet<char>, HashSet<char>, HashSet<char>);
+
+impl ElfGroup {
+    /// A group of `rucksacks`, or `None` unless there are exactly three.
+    pub fn new(rucksacks: &[Rucksack]) -> Option<Self> {
+        match rucksacks {
+            [f, s, t] => Some(ElfGroup(f.items(), s.items(), t.items())),
+            _ => None,
+        }
+    }
+
+    /// The priority of the badge all three carry, if there is one.
+    pub fn priority_shared_elem(&self) -> Option<u32> {
+        let ElfGroup(f, s, t) = self;
+
+        let first_two = f.intersection(s).cloned().collect::<HashSet<_>>();
+
+        let shared = first_two.intersection(t).next()?;
+
+        Some(elem_priority(shared))
+    }
+}
+
+const UNGROUPED: &str = "rucksacks don't split into groups of three";
+
+fn part2(rucksacks: &[Rucksack]) -> Result<u32, SolveError> {
+    let elf_groups = rucksacks
+        .chunks(3)
+        .map(|c| ElfGroup::new(c).ok_or(SolveError::new(DAY, UNGROUPED)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(elf_groups
+        .iter()
+        .map(|e| e.priority_shared_elem().unwrap_or(0))
+        .sum())
+}
+
+/// Both sums, holding no more than one group of rucksacks at a time.
+pub fn stream(reader: &mut dyn BufRead) -> Result<[Answer; 2], StreamError> {
+    let (mut total1, mut total2) = (0u64, 0u64);
+    let mut group = Vec::with_capacity(3);
+
+    for_each_parsed(reader, |rucksack: Rucksack| {
+        total1 += u64::from(rucksack.priority_shared_elem().unwrap_or(0));
+        group.push(rucksack);
+
+        if let Some(elf_group) = ElfGroup::new(&group) {
+            total2 += u64::from(elf_group.priority_shared_elem().unwrap_or(0));
+            group.clear();
+        }
+        Ok(())
+    })?;
+    if !group.is_empty() {
+        return Err(SolveError::new(DAY, UNGROUPED).into());
+    }
+
+    Ok([total1.into(), total2.into()])
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+
+    type Parsed = Vec<Rucksack>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(rucksacks: &Self::Parsed, _: &()) -> Result<Answer, SolveError> {
+        Ok(part1(rucksacks).into())
+    }
+
+    fn part2(rucksacks: &Self::Parsed, _: &()) -> Result<Answer, SolveError> {
+        part2(rucksacks).map(Answer::from)
+    }
+}
@@ -0,0 +1,172 @@
+//! Day 4: Camp Cleanup. Pairs of elves' section ranges.
+
+use crate::answer::Answer;
+use crate::error::{ParseError, SolveError, StreamError};
+use crate::parse::{lines, literal, parse_all, uint, Input, Parser};
+use crate::solution::Solution;
+use crate::stream::for_each_parsed;
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
+const DAY: u8 = 4;
+
+/// An inclusive range of section IDs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IRange(u32, u32);
+
+impl IRange {
+    pub fn new(start: u32, end: u32) -> Self {
+        IRange(start, end)
+    }
+
+    pub fn start(&self) -> u32 {
+        self.0
+    }
+
+    pub fn end(&self) -> u32 {
+        self.1
+    }
+
+    pub fn contains_range(&self, other: &IRange) -> bool {
+        let IRange(my_l, my_r) = self;
+        let IRange(other_l, other_r) = other;
+
+        my_l <= other_l && other_r <= my_r
+    }
+
+    pub fn overlaps_range(&self, other: &IRange) -> bool {
+        let IRange(my_l, my_r) = self;
+        let IRange(other_l, other_r) = other;
+
+        my_l <= other_r && my_r >= other_l
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElfPair(IRange, IRange);
+
+impl fmt::Display for IRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.0, self.1)
+    }
+}
+
+impl fmt::Display for ElfPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.0, self.1)
+    }
+}
+
+fn range(input: &mut Input) -> Result<IRange, ParseError> {
+    let start = uint().parse(input)?;
+    literal("-").parse(input)?;
+    let end = uint().parse(input)?;
+
+    Ok(IRange(start, end))
+}
+
+fn elf_pair(input: &mut Input) -> Result<ElfPair, ParseError> {
+    let l = range(input)?;
+    literal(",").parse(input)?;
+    let r = range(input)?;
+
+    Ok(ElfPair(l, r))
+}
+
+impl FromStr for ElfPair {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_all(DAY, s, elf_pair)
+    }
+}
+
+impl ElfPair {
+    pub fn new(left: IRange, right: IRange) -> Self {
+        ElfPair(left, right)
+    }
+
+    pub fn ranges(&self) -> (&IRange, &IRange) {
+        (&self.0, &self.1)
+    }
+
+    /// Whether either range contains the other.
+    pub fn is_subsumed(&self) -> bool {
+        let ElfPair(l, r) = self;
+
+        l.contains_range(r) || r.contains_range(l)
+    }
+
+    pub fn is_overlapping(&self) -> bool {
+        let ElfPair(l, r) = self;
+
+        l.overlaps_range(r) || r.overlaps_range(l)
+    }
+}
+
+fn parse_ranges(input: &str) -> Result<Vec<ElfPair>, ParseError> {
+    parse_all(DAY, input, lines(elf_pair))
+}
+
+fn part1(input: &[ElfPair]) -> u32 {
+    input.iter().filter(|ep| ep.is_subsumed()).count() as u32
+}
+
+fn part2(input: &[ElfPair]) -> u32 {
+    input.iter().filter(|ep| ep.is_overlapping()).count() as u32
+}
+
+/// Both counts, checking each pair as it is read.
+pub fn stream(reader: &mut dyn BufRead) -> Result<[Answer; 2], StreamError> {
+    let (mut subsumed, mut overlapping) = (0u64, 0u64);
+
+    for_each_parsed(reader, |pair: ElfPair| {
+        subsumed += u64::from(pair.is_subsumed());
+        overlapping += u64::from(pair.is_overlapping());
+        Ok(())
+    })?;
+
+    Ok([subsumed.into(), overlapping.into()])
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+
+    type Parsed = Vec<ElfPair>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_ranges(input)
+    }
+
+    fn part1(pairs: &Self::Parsed, _: &()) -> Result<Answer, SolveError> {
+        Ok(part1(pairs).into())
+    }
+
+    fn part2(pairs: &Self::Parsed, _: &()) -> Result<Answer, SolveError> {
+        Ok(part2(pairs).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn range() -> impl Strategy<Value = IRange> {
+        (any::<u32>(), any::<u32>()).prop_map(|(l, r)| IRange(l.min(r), l.max(r)))
+    }
+
+    proptest! {
+        #[test]
+        fn elf_pair_round_trips(l in range(), r in range()) {
+            let pair = ElfPair(l, r);
+
+            prop_assert_eq!(pair.to_string().parse::<ElfPair>()?, pair);
+        }
+    }
+}
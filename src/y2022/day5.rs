@@ -0,0 +1,449 @@
+//! Day 5: Supply Stacks. A drawing of stacked crates, and the crane's moves.
+
+use crate::answer::Answer;
+use crate::error::{parse_int, ParseError, ParseErrorKind, SolutionError, SolveError};
+use crate::grid::Grid;
+use crate::parse::{block, from_str, lines, literal, parse_all, try_map, uint, Input, Parser};
+use crate::solution::Solution;
+use crate::viz::Frame;
+use std::fmt;
+use std::str::FromStr;
+
+const DAY: u8 = 5;
+
+/// A crate labelled with a single printable ASCII character, as drawings can show.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crate {
+    label: char,
+}
+
+impl Crate {
+    /// `None` unless `label` is printable ASCII other than a space, so it can be drawn.
+    pub fn new(label: char) -> Option<Self> {
+        label.is_ascii_graphic().then_some(Crate { label })
+    }
+
+    pub fn label(&self) -> char {
+        self.label
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// A crane move. Stacks are counted from 0 here, though the puzzle numbers them from 1.
+pub struct MoveCommand {
+    num_crates: u32,
+    from_stack: usize,
+    to_stack: usize,
+}
+
+impl MoveCommand {
+    pub fn new(num_crates: u32, from_stack: usize, to_stack: usize) -> Self {
+        MoveCommand {
+            num_crates,
+            from_stack,
+            to_stack,
+        }
+    }
+
+    pub fn num_crates(&self) -> u32 {
+        self.num_crates
+    }
+
+    pub fn from_stack(&self) -> usize {
+        self.from_stack
+    }
+
+    pub fn to_stack(&self) -> usize {
+        self.to_stack
+    }
+}
+
+fn stack_number(input: &mut Input) -> Result<usize, ParseError> {
+    try_map(uint::<usize>(), |n| {
+        n.checked_sub(1)
+            .ok_or(ParseErrorKind::Unexpected("a stack number from 1"))
+    })
+    .parse(input)
+}
+
+fn move_command(input: &mut Input) -> Result<MoveCommand, ParseError> {
+    literal("move ").parse(input)?;
+    let num_crates = uint().parse(input)?;
+    literal(" from ").parse(input)?;
+    let from_stack = stack_number(input)?;
+    literal(" to ").parse(input)?;
+    let to_stack = stack_number(input)?;
+
+    Ok(MoveCommand {
+        num_crates,
+        from_stack,
+        to_stack,
+    })
+}
+
+impl FromStr for MoveCommand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_all(DAY, s, move_command)
+    }
+}
+
+impl fmt::Display for MoveCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.num_crates,
+            self.from_stack + 1,
+            self.to_stack + 1
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// The stacks of crates, each listed from the bottom up.
+pub struct State(Vec<Vec<Crate>>);
+
+impl FromStr for State {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = s.lines().rev().collect::<Vec<_>>();
+
+        let numbers_line = *lines.first().ok_or_else(|| {
+            ParseError::at(DAY, s, s, ParseErrorKind::UnexpectedEnd("stack numbers"))
+        })?;
+
+        let num_stacks = numbers_line
+            .split_whitespace()
+            .map(|tok| parse_int::<usize>(DAY, s, tok))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .max()
+            .ok_or_else(|| {
+                ParseError::at(
+                    DAY,
+                    s,
+                    numbers_line,
+                    ParseErrorKind::UnexpectedEnd("stack numbers"),
+                )
+            })?;
+
+        let mut stacks = vec![(); num_stacks]
+            .iter()
+            .map(|_| Vec::new())
+            .collect::<Vec<Vec<_>>>();
+
+        for &line in &lines[1..] {
+            let mut labels_idx = 0;
+            let mut line_idx = 1;
+            let mut labels: Vec<Option<Crate>> = vec![None; num_stacks];
+
+            while line_idx < line.len() {
+                let maybe_label = line.get(line_idx..line_idx + 1).ok_or_else(|| {
+                    ParseError::at(
+                        DAY,
+                        s,
+                        line,
+                        ParseErrorKind::Unexpected("ASCII crate labels"),
+                    )
+                })?;
+
+                if labels_idx >= num_stacks {
+                    return Err(ParseError::at(
+                        DAY,
+                        s,
+                        maybe_label,
+                        ParseErrorKind::Unexpected("a crate within the numbered stacks"),
+                    ));
+                }
+
+                if maybe_label != " " {
+                    let label = maybe_label.chars().next().and_then(Crate::new);
+                    labels[labels_idx] = Some(label.ok_or_else(|| {
+                        ParseError::at(
+                            DAY,
+                            s,
+                            maybe_label,
+                            ParseErrorKind::Unexpected("a printable crate label"),
+                        )
+                    })?);
+                }
+
+                labels_idx += 1;
+                line_idx += 4;
+            }
+
+            for (i, label) in labels.iter().enumerate() {
+                if let Some(c) = label {
+                    stacks[i].push(c.clone());
+                }
+            }
+        }
+
+        Ok(State(stacks))
+    }
+}
+
+/// Draws the stacks as the puzzle does: crates in columns four characters apart, bottom
+/// crates lowest, and the stack numbers along the bottom.
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let State(crates) = self;
+        let height = crates.iter().map(Vec::len).max().unwrap_or(0);
+
+        for level in (0..height).rev() {
+            let row = crates
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(c) => format!("[{}]", c.label),
+                    None => "   ".to_string(),
+                })
+                .collect::<Vec<_>>();
+            writeln!(f, "{}", row.join(" "))?;
+        }
+
+        let numbers = (1..=crates.len())
+            .map(|n| format!(" {:<3}", n))
+            .collect::<String>();
+        write!(f, "{}", numbers.trim_end())
+    }
+}
+
+impl State {
+    pub fn new(stacks: Vec<Vec<Crate>>) -> Self {
+        State(stacks)
+    }
+
+    pub fn stacks(&self) -> &[Vec<Crate>] {
+        &self.0
+    }
+
+    /// The labels of the crates on top of each stack.
+    pub fn get_message(&self) -> Result<String, SolveError> {
+        let State(crates) = self;
+
+        crates
+            .iter()
+            .map(|stack| {
+                stack
+                    .last()
+                    .map(|c| c.label)
+                    .ok_or(SolveError::new(DAY, "a stack ended up empty"))
+            })
+            .collect()
+    }
+
+    fn check_stacks(&self, command: &MoveCommand) -> Result<(), SolveError> {
+        let State(crates) = self;
+
+        if command.from_stack < crates.len() && command.to_stack < crates.len() {
+            Ok(())
+        } else {
+            Err(SolveError::new(
+                DAY,
+                "a move names a stack that doesn't exist",
+            ))
+        }
+    }
+
+    /// Moves crates one at a time, as the CrateMover 9000 does.
+    pub fn step(&mut self, command: &MoveCommand) -> Result<(), SolveError> {
+        self.check_stacks(command)?;
+
+        let State(crates) = self;
+        let &MoveCommand {
+            num_crates,
+            from_stack,
+            to_stack,
+        } = command;
+
+        for _ in 0..num_crates {
+            let crate_ = crates[from_stack]
+                .pop()
+                .ok_or(SolveError::new(DAY, "a move takes from an empty stack"))?;
+            crates[to_stack].push(crate_);
+        }
+
+        Ok(())
+    }
+
+    pub fn step_many(&mut self, commands: &[MoveCommand]) -> Result<(), SolveError> {
+        for command in commands {
+            self.step(command)?;
+        }
+
+        Ok(())
+    }
+
+    /// Moves crates all at once, keeping their order, as the CrateMover 9001 does.
+    pub fn multi_step(&mut self, command: &MoveCommand) -> Result<(), SolveError> {
+        self.check_stacks(command)?;
+
+        let State(crates) = self;
+        let &MoveCommand {
+            num_crates,
+            from_stack,
+            to_stack,
+        } = command;
+
+        let mut moved_crates: Vec<Crate> = Vec::new();
+
+        for _ in 0..num_crates {
+            let crate_ = crates[from_stack]
+                .pop()
+                .ok_or(SolveError::new(DAY, "a move takes from an empty stack"))?;
+            moved_crates.push(crate_);
+        }
+
+        moved_crates.reverse();
+
+        for crate_ in moved_crates {
+            crates[to_stack].push(crate_);
+        }
+
+        Ok(())
+    }
+
+    /// The stacks drawn as in the puzzle, room for `height` crates, with their numbers below.
+    pub fn render(&self, height: usize) -> Grid<char> {
+        let State(crates) = self;
+        let width = (crates.len() * 4).saturating_sub(1);
+
+        Grid::from_fn(width, height + 1, |x, y| {
+            let (stack, offset) = (x / 4, x % 4);
+
+            if y == height {
+                match offset {
+                    1 => char::from_digit((stack as u32 + 1) % 10, 10).unwrap(),
+                    _ => ' ',
+                }
+            } else {
+                match (crates[stack].get(height - 1 - y), offset) {
+                    (Some(_), 0) => '[',
+                    (Some(c), 1) => c.label,
+                    (Some(_), 2) => ']',
+                    _ => ' ',
+                }
+            }
+        })
+    }
+
+    pub fn multi_step_many(&mut self, commands: &[MoveCommand]) -> Result<(), SolveError> {
+        for command in commands {
+            self.multi_step(command)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_input(input: &str) -> Result<(State, Vec<MoveCommand>), ParseError> {
+    parse_all(DAY, input, |input: &mut Input| {
+        let state = block(from_str::<State>()).parse(input)?;
+        let commands = lines(move_command).parse(input)?;
+
+        Ok((state, commands))
+    })
+}
+
+/// A frame per move of the CrateMover 9000, starting from the drawing.
+pub fn visualize(input: &str) -> Result<Vec<Frame>, SolutionError> {
+    let (mut state, commands) = parse_input(input)?;
+    let height = state.0.iter().map(Vec::len).sum();
+    let mut frames = vec![Frame::new(state.render(height))];
+
+    for command in &commands {
+        state.step(command)?;
+        frames.push(Frame::new(state.render(height)));
+    }
+
+    Ok(frames)
+}
+
+fn part1((state, commands): &(State, Vec<MoveCommand>)) -> Result<String, SolveError> {
+    let mut state = state.clone();
+
+    state.step_many(commands)?;
+
+    state.get_message()
+}
+
+fn part2((state, commands): &(State, Vec<MoveCommand>)) -> Result<String, SolveError> {
+    let mut state = state.clone();
+
+    state.multi_step_many(commands)?;
+
+    state.get_message()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+
+    type Parsed = (State, Vec<MoveCommand>);
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(parsed: &Self::Parsed, _: &()) -> Result<Answer, SolveError> {
+        part1(parsed).map(Answer::from)
+    }
+
+    fn part2(parsed: &Self::Parsed, _: &()) -> Result<Answer, SolveError> {
+        part2(parsed).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn move_command(num_stacks: usize) -> impl Strategy<Value = MoveCommand> {
+        (any::<u32>(), 0..num_stacks, 0..num_stacks).prop_map(
+            |(num_crates, from_stack, to_stack)| MoveCommand {
+                num_crates,
+                from_stack,
+                to_stack,
+            },
+        )
+    }
+
+    fn state() -> impl Strategy<Value = State> {
+        let label = (b'!'..=b'~').prop_map(|label| Crate::new(char::from(label)).unwrap());
+        let stack = prop::collection::vec(label, 0..8);
+
+        prop::collection::vec(stack, 1..=12).prop_map(State)
+    }
+
+    proptest! {
+        #[test]
+        fn move_command_round_trips(command in move_command(100)) {
+            prop_assert_eq!(command.to_string().parse::<MoveCommand>()?, command);
+        }
+
+        #[test]
+        fn state_round_trips(state in state()) {
+            prop_assert_eq!(state.to_string().parse::<State>()?, state);
+        }
+
+        #[test]
+        fn input_round_trips(
+            (state, commands) in state().prop_flat_map(|state| {
+                let num_stacks = state.0.len();
+                (Just(state), prop::collection::vec(move_command(num_stacks), 1..20))
+            })
+        ) {
+            let commands_text = commands.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+            let input = format!("{}\n\n{}", state, commands_text.join("\n"));
+
+            prop_assert_eq!(parse_input(&input)?, (state, commands));
+        }
+    }
+}
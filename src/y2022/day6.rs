@@ -0,0 +1,91 @@
+//! Day 6: Tuning Trouble. A datastream with start-of-packet and start-of-message markers.
+
+use crate::answer::Answer;
+use crate::error::{ParseError, SolveError};
+use crate::params::{self, ParamError, Params};
+use crate::solution::Solution;
+use std::any::Any;
+use std::collections::HashSet;
+
+const DAY: u8 = 6;
+
+/// How many characters are read before the last `n` are all different, if they ever are.
+pub fn find_n_distinct(input: &str, n: usize) -> Option<usize> {
+    for (starting_index, window) in input.as_bytes().windows(n).enumerate() {
+        if HashSet::<u8>::from_iter(window.iter().cloned()).len() == n {
+            return Some(starting_index + n);
+        }
+    }
+
+    None
+}
+
+/// How many different characters make each kind of marker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day6Params {
+    pub packet_window: usize,
+    pub message_window: usize,
+}
+
+impl Default for Day6Params {
+    fn default() -> Self {
+        Day6Params {
+            packet_window: 4,
+            message_window: 14,
+        }
+    }
+}
+
+impl Params for Day6Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), ParamError> {
+        match key {
+            "packet_window" => self.packet_window = params::positive(key, value)?,
+            "message_window" => self.message_window = params::positive(key, value)?,
+            _ => return Err(ParamError::UnknownKey(key.to_string())),
+        }
+        Ok(())
+    }
+
+    fn values(&self) -> Vec<(&'static str, Option<String>)> {
+        vec![
+            ("packet_window", Some(self.packet_window.to_string())),
+            ("message_window", Some(self.message_window.to_string())),
+        ]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn part1(input: &str, params: &Day6Params) -> Result<usize, SolveError> {
+    find_n_distinct(input, params.packet_window)
+        .ok_or(SolveError::new(DAY, "no start-of-packet marker"))
+}
+
+fn part2(input: &str, params: &Day6Params) -> Result<usize, SolveError> {
+    find_n_distinct(input, params.message_window)
+        .ok_or(SolveError::new(DAY, "no start-of-message marker"))
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+
+    type Parsed = String;
+    type Params = Day6Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(input.trim_end().to_string())
+    }
+
+    fn part1(signal: &Self::Parsed, params: &Day6Params) -> Result<Answer, SolveError> {
+        part1(signal, params).map(Answer::from)
+    }
+
+    fn part2(signal: &Self::Parsed, params: &Day6Params) -> Result<Answer, SolveError> {
+        part2(signal, params).map(Answer::from)
+    }
+}
@@ -0,0 +1,216 @@
+//! Day 7: No Space Left On Device. A terminal transcript exploring a filesystem.
+
+use crate::answer::Answer;
+use crate::error::{parse_int, ParseError, ParseErrorKind, SolveError};
+use crate::params::{self, ParamError, Params};
+use crate::solution::Solution;
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+const DAY: u8 = 7;
+
+/// Every directory's total size, by absolute path.
+#[derive(Debug, Clone)]
+pub struct FileTree(BTreeMap<PathBuf, usize>);
+
+impl Default for FileTree {
+    fn default() -> Self {
+        FileTree::new()
+    }
+}
+
+impl FileTree {
+    /// A tree holding just an empty `/`.
+    pub fn new() -> Self {
+        FileTree(BTreeMap::from([(PathBuf::from("/"), 0)]))
+    }
+
+    /// Records a directory, without changing any sizes if it is already known.
+    pub fn add_dir(&mut self, path: impl Into<PathBuf>) {
+        self.0.entry(path.into()).or_insert(0);
+    }
+
+    /// Records a file of `size` at `path`, adding its size to every directory above it.
+    pub fn add_file(&mut self, path: impl AsRef<Path>, size: usize) {
+        for ancestor in path.as_ref().ancestors().skip(1) {
+            *self.0.entry(ancestor.to_path_buf()).or_insert(0) += size;
+        }
+    }
+
+    /// The total size of the directory at `path`, if there is one.
+    pub fn size_of(&self, path: impl AsRef<Path>) -> Option<usize> {
+        self.0.get(path.as_ref()).copied()
+    }
+
+    /// Every directory and its total size, in path order.
+    pub fn directories(&self) -> impl Iterator<Item = (&Path, usize)> {
+        self.0.iter().map(|(path, &size)| (path.as_path(), size))
+    }
+
+    /// The sum of the sizes of the directories no bigger than `limit_size`.
+    pub fn total_size_below_limit(&self, limit_size: usize) -> usize {
+        let FileTree(directories) = self;
+
+        directories
+            .iter()
+            .filter_map(|(path, &size)| {
+                if path.extension().is_some() || size > limit_size {
+                    None
+                } else {
+                    Some(size)
+                }
+            })
+            .sum()
+    }
+
+    /// The size of the smallest directory whose deletion leaves `total_needed_space` free on
+    /// a disk of `disk_size`, or `None` if there is none or the disk is too small to hold
+    /// the tree.
+    pub fn smallest_size_above_limit(
+        &self,
+        disk_size: usize,
+        total_needed_space: usize,
+    ) -> Option<usize> {
+        let FileTree(directories) = self;
+
+        let used_space = *directories.get(&*PathBuf::from("/")).unwrap();
+        let free_space = disk_size.checked_sub(used_space)?;
+        let additional_needed_space = total_needed_space.saturating_sub(free_space);
+
+        directories
+            .iter()
+            .filter_map(|(path, &size)| {
+                if path.extension().is_some() || size < additional_needed_space {
+                    None
+                } else {
+                    Some(size)
+                }
+            })
+            .min()
+    }
+}
+
+fn parse_input(input: &str) -> Result<FileTree, ParseError> {
+    let mut tree = FileTree::new();
+    let mut current_dir = PathBuf::from("/");
+
+    for (i, line) in input.lines().enumerate().skip(1) {
+        if line.starts_with("$ ls") {
+            continue;
+        } else if line.starts_with("$ cd /") {
+            current_dir = PathBuf::from("/");
+        } else if line.starts_with("$ cd ..") {
+            if let Some(parent) = current_dir.parent() {
+                current_dir = parent.to_path_buf();
+            }
+        } else if let Some(dir_name) = line.strip_prefix("$ cd ") {
+            let dir_name = dir_name.to_string();
+            let new_path = current_dir.join(dir_name);
+            tree.add_dir(new_path.clone());
+            current_dir = new_path;
+        } else {
+            let (type_or_size, name) = line.split_once(' ').ok_or_else(|| {
+                ParseError::at(DAY, line, line, ParseErrorKind::MissingDelimiter(" ")).below(i)
+            })?;
+            let new_path = current_dir.join(name);
+
+            if type_or_size == "dir" {
+                tree.add_dir(new_path);
+            } else {
+                let size = parse_int::<usize>(DAY, line, type_or_size).map_err(|e| e.below(i))?;
+                tree.add_file(new_path, size);
+            }
+        }
+    }
+
+    Ok(tree)
+}
+
+impl FromStr for FileTree {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_input(s)
+    }
+}
+
+/// The sizes the puzzle asks about.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day7Params {
+    /// The largest directory part 1 counts.
+    pub small_dir_limit: usize,
+    /// The free space part 2 needs.
+    pub needed_space: usize,
+    pub disk_size: usize,
+}
+
+impl Default for Day7Params {
+    fn default() -> Self {
+        Day7Params {
+            small_dir_limit: 100000,
+            needed_space: 30000000,
+            disk_size: 70000000,
+        }
+    }
+}
+
+impl Params for Day7Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), ParamError> {
+        match key {
+            "small_dir_limit" => self.small_dir_limit = params::number(key, value)?,
+            "needed_space" => self.needed_space = params::number(key, value)?,
+            "disk_size" => self.disk_size = params::number(key, value)?,
+            _ => return Err(ParamError::UnknownKey(key.to_string())),
+        }
+        Ok(())
+    }
+
+    fn values(&self) -> Vec<(&'static str, Option<String>)> {
+        vec![
+            ("small_dir_limit", Some(self.small_dir_limit.to_string())),
+            ("needed_space", Some(self.needed_space.to_string())),
+            ("disk_size", Some(self.disk_size.to_string())),
+        ]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn part1(input: &FileTree, params: &Day7Params) -> usize {
+    input.total_size_below_limit(params.small_dir_limit)
+}
+
+fn part2(input: &FileTree, params: &Day7Params) -> Result<usize, SolveError> {
+    input
+        .smallest_size_above_limit(params.disk_size, params.needed_space)
+        .ok_or(SolveError::new(
+            DAY,
+            "no directory is big enough to free the space",
+        ))
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+
+    type Parsed = FileTree;
+    type Params = Day7Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(tree: &Self::Parsed, params: &Day7Params) -> Result<Answer, SolveError> {
+        Ok(part1(tree, params).into())
+    }
+
+    fn part2(tree: &Self::Parsed, params: &Day7Params) -> Result<Answer, SolveError> {
+        part2(tree, params).map(Answer::from)
+    }
+}
@@ -0,0 +1,122 @@
+//! Day 8: Treetop Tree House. A grid of tree heights.
+
+use crate::answer::Answer;
+use crate::error::{ParseError, SolveError};
+use crate::geom::Dir4;
+use crate::grid::Grid;
+use crate::solution::Solution;
+use std::str::FromStr;
+
+const DAY: u8 = 8;
+
+#[derive(Debug)]
+pub struct Forest {
+    trees: Grid<u8>,
+    visible_trees: Grid<bool>,
+}
+
+impl FromStr for Forest {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trees = Grid::parse(DAY, s, "a tree height 0-9", |c| {
+            c.to_digit(10).map(|d| d as u8)
+        })?;
+
+        Ok(Forest::new(trees))
+    }
+}
+
+impl Forest {
+    /// A forest of `trees` heights, working out which trees can be seen from outside.
+    pub fn new(trees: Grid<u8>) -> Self {
+        let visible_trees = Grid::from_fn(trees.width(), trees.height(), |x, y| {
+            let tree = trees[(x, y)];
+
+            Dir4::ALL
+                .into_iter()
+                .any(|dir| trees.ray(x, y, dir).all(|(_, &other)| other < tree))
+        });
+
+        Forest {
+            trees,
+            visible_trees,
+        }
+    }
+
+    pub fn trees(&self) -> &Grid<u8> {
+        &self.trees
+    }
+
+    /// Whether the tree at `(x, y)` can be seen from outside the forest.
+    pub fn is_visible(&self, x: usize, y: usize) -> bool {
+        self.visible_trees[(x, y)]
+    }
+
+    pub fn count_visible_trees(&self) -> usize {
+        self.visible_trees.iter().filter(|&&c| c).count()
+    }
+
+    /// The scenic score of the tree at `(x0, y0)`: the product of how far it can see each way.
+    pub fn calculate_score(&self, x0: usize, y0: usize) -> usize {
+        let tree = self.trees[(x0, y0)];
+
+        Dir4::ALL
+            .into_iter()
+            .map(|dir| {
+                let mut score = 0;
+
+                for (_, &other) in self.trees.ray(x0, y0, dir) {
+                    score += 1;
+                    if other >= tree {
+                        break;
+                    }
+                }
+
+                score
+            })
+            .product()
+    }
+
+    pub fn calculate_max_score(&self) -> usize {
+        self.trees
+            .positions()
+            .map(|(x, y)| self.calculate_score(x, y))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Forest, ParseError> {
+    input.parse()
+}
+
+fn part1(forest: &Forest) -> usize {
+    forest.count_visible_trees()
+}
+
+fn part2(forest: &Forest) -> usize {
+    forest.calculate_max_score()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+
+    type Parsed = Forest;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(forest: &Self::Parsed, _: &()) -> Result<Answer, SolveError> {
+        Ok(part1(forest).into())
+    }
+
+    fn part2(forest: &Self::Parsed, _: &()) -> Result<Answer, SolveError> {
+        Ok(part2(forest).into())
+    }
+}
@@ -0,0 +1,388 @@
+//! Day 9: Rope Bridge. Moves of a rope's head, and the squares its tail visits.
+
+use crate::answer::Answer;
+use crate::error::{ParseError, SolveError, StreamError};
+use crate::geom::{Dir4, Point2};
+use crate::grid::Grid;
+use crate::params::{self, ParamError, Params};
+use crate::parse::{lines, literal, one_of, parse_all, uint, Input, Parser};
+use crate::solution::Solution;
+use crate::stream::for_each_parsed;
+use crate::viz::Frame;
+use std::any::Any;
+use std::collections::HashSet;
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
+const DAY: u8 = 9;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Moving the head some distance in one direction.
+pub struct Move(Dir4, usize);
+
+impl Move {
+    pub fn new(dir: Dir4, distance: usize) -> Self {
+        Move(dir, distance)
+    }
+
+    pub fn dir(&self) -> Dir4 {
+        self.0
+    }
+
+    pub fn distance(&self) -> usize {
+        self.1
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let dir = match self.0 {
+            Dir4::Up => "U",
+            Dir4::Down => "D",
+            Dir4::Left => "L",
+            Dir4::Right => "R",
+        };
+
+        write!(f, "{} {}", dir, self.1)
+    }
+}
+
+fn move_(input: &mut Input) -> Result<Move, ParseError> {
+    let dir = one_of(
+        &[
+            ("U", Dir4::Up),
+            ("D", Dir4::Down),
+            ("L", Dir4::Left),
+            ("R", Dir4::Right),
+        ],
+        "one of U, D, L or R",
+    )
+    .parse(input)?;
+    literal(" ").parse(input)?;
+    let dist = uint().parse(input)?;
+
+    Ok(Move(dir, dist))
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_all(DAY, s, move_)
+    }
+}
+
+/// Drags `knot` one step (possibly diagonally) towards `leader` once they stop touching.
+pub fn follow(knot: &mut Point2, leader: Point2) {
+    if knot.chebyshev(leader) > 1 {
+        *knot += (leader - *knot).signum();
+    }
+}
+
+/// Moves the first knot a step in `dir` and drags the rest after it.
+pub fn pull(knots: &mut [Point2], dir: Dir4) {
+    knots[0] = knots[0].step(dir);
+
+    for i in 1..knots.len() {
+        let leader = knots[i - 1];
+        follow(&mut knots[i], leader);
+    }
+}
+
+/// A two-knot rope working through its moves, tracking where its tail has been.
+#[derive(Debug)]
+pub struct Rope {
+    moves: Vec<Move>,
+    visited_coords: HashSet<Point2>,
+    next_step: usize,
+    curr_head: Point2,
+    curr_tail: Point2,
+}
+
+impl Rope {
+    pub fn new(moves: Vec<Move>) -> Self {
+        Rope {
+            moves,
+            visited_coords: HashSet::from([Point2::ORIGIN]),
+            next_step: 0,
+            curr_head: Point2::ORIGIN,
+            curr_tail: Point2::ORIGIN,
+        }
+    }
+
+    pub fn head(&self) -> Point2 {
+        self.curr_head
+    }
+
+    pub fn tail(&self) -> Point2 {
+        self.curr_tail
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.next_step == self.moves.len()
+    }
+
+    /// Makes the next move. Panics if every move has been made.
+    pub fn step(&mut self) {
+        let &Move(dir, num_steps) = &self.moves[self.next_step];
+
+        for _ in 0..num_steps {
+            self.curr_head = self.curr_head.step(dir);
+            follow(&mut self.curr_tail, self.curr_head);
+
+            self.visited_coords.insert(self.curr_tail);
+        }
+
+        self.next_step += 1;
+    }
+
+    pub fn step_all(&mut self) {
+        while !self.is_finished() {
+            self.step();
+        }
+    }
+
+    /// The squares the tail has visited so far.
+    pub fn visited(&self) -> &HashSet<Point2> {
+        &self.visited_coords
+    }
+
+    pub fn count_visited_coords(&self) -> usize {
+        self.visited_coords.len()
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Move>, ParseError> {
+    parse_all(DAY, input, lines(move_))
+}
+
+fn part1(moves: &[Move]) -> usize {
+    let mut rope = Rope::new(moves.to_vec());
+
+    rope.step_all();
+    rope.count_visited_coords()
+}
+
+/// A rope of many knots working through its moves, tracking where its tail has been.
+pub struct LongRope {
+    moves: Vec<Move>,
+    visited_coords: HashSet<Point2>,
+    next_step: usize,
+    knots: Vec<Point2>,
+}
+
+impl LongRope {
+    /// The puzzle's rope of ten knots.
+    pub fn new(moves: Vec<Move>) -> Self {
+        LongRope::with_tail_knots(moves, 9)
+    }
+
+    /// A rope of `tail_knots` knots behind the head.
+    pub fn with_tail_knots(moves: Vec<Move>, tail_knots: usize) -> Self {
+        LongRope {
+            moves,
+            visited_coords: HashSet::from([Point2::ORIGIN]),
+            next_step: 0,
+            knots: vec![Point2::ORIGIN; tail_knots + 1],
+        }
+    }
+
+    /// The knots, head first.
+    pub fn knots(&self) -> &[Point2] {
+        &self.knots
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.next_step == self.moves.len()
+    }
+
+    /// Makes the next move. Panics if every move has been made.
+    pub fn step(&mut self) {
+        let &Move(dir, num_steps) = &self.moves[self.next_step];
+
+        for _ in 0..num_steps {
+            pull(&mut self.knots, dir);
+            self.visited_coords.insert(*self.knots.last().unwrap());
+        }
+
+        self.next_step += 1;
+    }
+
+    pub fn step_all(&mut self) {
+        while !self.is_finished() {
+            self.step();
+        }
+    }
+
+    /// The squares the tail has visited so far.
+    pub fn visited(&self) -> &HashSet<Point2> {
+        &self.visited_coords
+    }
+
+    pub fn count_visited_coords(&self) -> usize {
+        self.visited_coords.len()
+    }
+}
+
+/// How many frames an animation of the long rope is squeezed into.
+const MAX_FRAMES: usize = 200;
+
+/// The long rope's knots over the squares its tail has visited, `H` at the head.
+pub fn visualize(input: &str) -> Result<Vec<Frame>, ParseError> {
+    let moves = parse_input(input)?;
+    let moves_per_frame = moves.len().div_ceil(MAX_FRAMES).max(1);
+    let mut long_rope = LongRope::new(moves);
+
+    let mut snapshots = vec![(long_rope.knots.clone(), long_rope.visited_coords.clone())];
+    while !long_rope.is_finished() {
+        for _ in 0..moves_per_frame {
+            if !long_rope.is_finished() {
+                long_rope.step();
+            }
+        }
+        snapshots.push((long_rope.knots.clone(), long_rope.visited_coords.clone()));
+    }
+
+    let every_point = || {
+        snapshots
+            .iter()
+            .flat_map(|(knots, visited)| knots.iter().chain(visited))
+    };
+    let min_x = every_point().map(|p| p.x).min().unwrap_or(0);
+    let max_x = every_point().map(|p| p.x).max().unwrap_or(0);
+    let min_y = every_point().map(|p| p.y).min().unwrap_or(0);
+    let max_y = every_point().map(|p| p.y).max().unwrap_or(0);
+    let corner = Point2::new(min_x, min_y);
+
+    let frames = snapshots
+        .iter()
+        .map(|(knots, visited)| {
+            let cells = Grid::from_fn(
+                (max_x - min_x + 1) as usize,
+                (max_y - min_y + 1) as usize,
+                |x, y| {
+                    let p = Point2::new(x as isize, y as isize) + corner;
+                    if visited.contains(&p) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                },
+            );
+
+            knots
+                .iter()
+                .enumerate()
+                .rev()
+                .fold(Frame::new(cells), |frame, (i, &knot)| {
+                    let glyph = match i {
+                        0 => 'H',
+                        i => char::from_digit(i as u32, 10).unwrap(),
+                    };
+                    frame.with_sprite(knot - corner, glyph)
+                })
+        })
+        .collect();
+
+    Ok(frames)
+}
+
+/// The length of part 2's rope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day9Params {
+    /// Knots behind the head.
+    pub tail_knots: usize,
+}
+
+impl Default for Day9Params {
+    fn default() -> Self {
+        Day9Params { tail_knots: 9 }
+    }
+}
+
+impl Params for Day9Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), ParamError> {
+        match key {
+            "tail_knots" => self.tail_knots = params::positive(key, value)?,
+            _ => return Err(ParamError::UnknownKey(key.to_string())),
+        }
+        Ok(())
+    }
+
+    fn values(&self) -> Vec<(&'static str, Option<String>)> {
+        vec![("tail_knots", Some(self.tail_knots.to_string()))]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn part2(moves: &[Move], params: &Day9Params) -> usize {
+    let mut long_rope = LongRope::with_tail_knots(moves.to_vec(), params.tail_knots);
+
+    long_rope.step_all();
+    long_rope.count_visited_coords()
+}
+
+/// Both counts from moving one long rope as the moves are read; its second knot is where a
+/// two-knot rope's tail would be. Memory grows with the squares the tails visit, not with the
+/// number of moves.
+pub fn stream(reader: &mut dyn BufRead, params: &Day9Params) -> Result<[Answer; 2], StreamError> {
+    let mut knots = vec![Point2::ORIGIN; params.tail_knots.max(1) + 1];
+    let mut visited_short = HashSet::from([Point2::ORIGIN]);
+    let mut visited_long = HashSet::from([Point2::ORIGIN]);
+
+    for_each_parsed(reader, |Move(dir, num_steps)| {
+        for _ in 0..num_steps {
+            pull(&mut knots, dir);
+            visited_short.insert(knots[1]);
+            visited_long.insert(knots[params.tail_knots]);
+        }
+        Ok(())
+    })?;
+
+    Ok([visited_short.len().into(), visited_long.len().into()])
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = DAY;
+
+    type Parsed = Vec<Move>;
+    type Params = Day9Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(moves: &Self::Parsed, _: &Day9Params) -> Result<Answer, SolveError> {
+        Ok(part1(moves).into())
+    }
+
+    fn part2(moves: &Self::Parsed, params: &Day9Params) -> Result<Answer, SolveError> {
+        Ok(part2(moves, params).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn move_() -> impl Strategy<Value = Move> {
+        let dir = prop::sample::select(Dir4::ALL.to_vec());
+
+        (dir, any::<usize>()).prop_map(|(dir, dist)| Move(dir, dist))
+    }
+
+    proptest! {
+        #[test]
+        fn move_round_trips(m in move_()) {
+            prop_assert_eq!(m.to_string().parse::<Move>()?, m);
+        }
+    }
+}
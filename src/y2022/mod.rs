@@ -0,0 +1,36 @@
+//! The 2022 puzzles. Each day's module has its `Solution` and the types it is built from,
+//! which can be used on their own.
+
+use crate::registry::{params_of, Entry};
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub const YEAR: u16 = 2022;
+
+pub(crate) static ENTRIES: &[Entry] = &[
+    Entry::of::<day1::Day1>().with_streaming(|reader, _| day1::stream(reader)),
+    Entry::of::<day2::Day2>().with_streaming(|reader, _| day2::stream(reader)),
+    Entry::of::<day3::Day3>().with_streaming(|reader, _| day3::stream(reader)),
+    Entry::of::<day4::Day4>().with_streaming(|reader, _| day4::stream(reader)),
+    Entry::of::<day5::Day5>().with_visualization(day5::visualize),
+    Entry::of::<day6::Day6>(),
+    Entry::of::<day7::Day7>(),
+    Entry::of::<day8::Day8>(),
+    Entry::of::<day9::Day9>()
+        .with_visualization(|input| Ok(day9::visualize(input)?))
+        .with_streaming(|reader, params| day9::stream(reader, params_of(params))),
+    Entry::of::<day10::Day10>()
+        .with_visualization(day10::visualize)
+        .with_streaming(|reader, params| day10::stream(reader, params_of(params))),
+    Entry::of::<day11::Day11>(),
+];
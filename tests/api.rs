@@ -0,0 +1,286 @@
+use advent_2022::answer::Answer;
+use advent_2022::error::SolutionError;
+use advent_2022::geom::{Dir4, Point2};
+use advent_2022::registry;
+use advent_2022::solution::Solution;
+use advent_2022::y2022::day1::rebalance::{self, Method};
+use advent_2022::y2022::day1::{self, Day1, ElfSummary, Inventory, Ranked};
+use advent_2022::y2022::day10::{Instr, Program, SIGNAL_CYCLES};
+use advent_2022::y2022::day11::{Monkey, MonkeyGroup, Operation};
+use advent_2022::y2022::day2::{Game, GameError, Outcome, Reading, Response, Round, Shape};
+use advent_2022::y2022::day4::{ElfPair, IRange};
+use advent_2022::y2022::day5::{Crate, MoveCommand, State};
+use advent_2022::y2022::day7::FileTree;
+use advent_2022::y2022::day9::{LongRope, Move, Rope};
+use std::num::NonZeroU128;
+
+#[test]
+fn file_tree_sums_files_into_every_ancestor() {
+    let mut tree = FileTree::new();
+    tree.add_dir("/a");
+    tree.add_file("/a/b.txt", 100);
+    tree.add_file("/a/e/c.txt", 20);
+    tree.add_file("/d.txt", 3);
+
+    assert_eq!(tree.size_of("/"), Some(123));
+    assert_eq!(tree.size_of("/a"), Some(120));
+    assert_eq!(tree.size_of("/a/e"), Some(20));
+    assert_eq!(tree.size_of("/b"), None);
+    assert_eq!(tree.total_size_below_limit(100), 20);
+    assert_eq!(tree.directories().count(), 3);
+}
+
+#[test]
+fn program_steps_one_instruction_at_a_time() {
+    let mut program = Program::new(Vec::new());
+    assert_eq!(program.frame().flatten()[(0, 0)], '=');
+
+    program
+        .execute(&Instr::AddX(3), &SIGNAL_CYCLES, &mut |_| {})
+        .unwrap();
+    assert_eq!((program.cycle(), program.x()), (2, 4));
+
+    program
+        .execute(&Instr::Noop, &SIGNAL_CYCLES, &mut |_| {})
+        .unwrap();
+    assert_eq!((program.cycle(), program.x()), (3, 4));
+    assert!(program.display()[(0, 0)]);
+
+    let mut program = Program::new(vec![Instr::AddX(isize::MAX), Instr::AddX(1)]);
+    assert!(program.run_calculating_signal_strengths(&[]).is_err());
+}
+
+#[test]
+fn monkeys_play_rounds() {
+    let monkeys = vec![
+        Monkey::new(vec![79, 98], Operation::Mul(19), 23, 1, 1),
+        Monkey::new(vec![54], Operation::Add(6), 19, 0, 0),
+    ];
+    let mut group = MonkeyGroup::new(monkeys);
+    assert_eq!(group.base().map(NonZeroU128::get), Some(23 * 19));
+
+    group.play_round(true).unwrap();
+    assert_eq!(group.monkeys()[0].inspected_item_count(), 2);
+    assert_eq!(group.monkeys()[1].inspected_item_count(), 3);
+    assert_eq!(group.monkey_business(), Some(6));
+
+    let huge = vec![
+        Monkey::new(vec![u128::MAX / 2], Operation::Square, u128::MAX, 1, 1),
+        Monkey::new(Vec::new(), Operation::Add(1), u128::MAX, 0, 0),
+    ];
+    let mut group = MonkeyGroup::new(huge);
+    assert_eq!(group.base(), None);
+    assert!(group.play_round(true).is_err());
+
+    let never_divisible = vec![
+        Monkey::new(vec![5], Operation::Add(1), 0, 1, 1),
+        Monkey::new(Vec::new(), Operation::Add(1), 7, 0, 0),
+    ];
+    let mut group = MonkeyGroup::new(never_divisible);
+    assert_eq!(group.base(), None);
+    group.play_round(false).unwrap();
+}
+
+#[test]
+fn crates_move_one_at_a_time_or_together() {
+    let stacks = vec![
+        vec![Crate::new('A').unwrap(), Crate::new('B').unwrap()],
+        vec![Crate::new('C').unwrap()],
+    ];
+    let command = MoveCommand::new(2, 0, 1);
+
+    let mut one_at_a_time = State::new(stacks.clone());
+    one_at_a_time.step(&command).unwrap();
+    assert!(one_at_a_time.get_message().is_err());
+    assert_eq!(one_at_a_time.stacks()[1].last().unwrap().label(), 'A');
+
+    let mut together = State::new(stacks);
+    together.multi_step(&command).unwrap();
+    assert_eq!(together.stacks()[1].last().unwrap().label(), 'B');
+
+    assert_eq!(Crate::new(' '), None);
+    assert_eq!(Crate::new('é'), None);
+}
+
+#[test]
+fn ranges_and_ropes() {
+    let pair = ElfPair::new(IRange::new(2, 8), IRange::new(3, 7));
+    assert!(pair.is_subsumed() && pair.is_overlapping());
+    assert_eq!(pair.ranges().1.end(), 7);
+
+    let moves = vec![Move::new(Dir4::Right, 4), Move::new(Dir4::Up, 4)];
+    let mut rope = Rope::new(moves.clone());
+    rope.step();
+    assert_eq!(rope.tail(), Point2::new(3, 0));
+    rope.step_all();
+    assert!(rope.is_finished());
+
+    let mut long_rope = LongRope::new(moves);
+    long_rope.step_all();
+    assert_eq!(long_rope.knots().len(), 10);
+    assert_eq!(long_rope.count_visited_coords(), 1);
+}
+
+#[test]
+fn top_k_ranks_elves_with_their_indices() {
+    let elves = vec![vec![1000, 2000], vec![4000], vec![5000, 6000], vec![3000]];
+
+    assert_eq!(
+        day1::top_k(day1::totals(&elves).unwrap(), 2),
+        [
+            Ranked {
+                index: 2,
+                total: 11000
+            },
+            Ranked {
+                index: 1,
+                total: 4000
+            }
+        ]
+    );
+    assert_eq!(day1::top_k(day1::totals(&elves).unwrap(), 10).len(), 4);
+    assert_eq!(day1::top_k([5, 7, 7], 2)[1].index, 2);
+}
+
+#[test]
+fn inventory_summarises_the_elves() {
+    let elves = vec![
+        vec![1000, 2000, 3000],
+        vec![4000],
+        vec![5000, 6000],
+        vec![7000, 8000, 9000],
+        vec![10000],
+    ];
+    let inventory = Inventory::new(&elves);
+
+    assert_eq!(inventory.total(), 55000);
+    assert_eq!(inventory.mean(), Some(11000.0));
+    assert_eq!(inventory.median(), Some(10000.0));
+    assert_eq!(inventory.percentile(25.0), Some(6000.0));
+    assert_eq!(inventory.percentile(90.0), Some(18800.0));
+    assert_eq!(
+        inventory.item_counts().into_iter().collect::<Vec<_>>(),
+        [(1, 2), (2, 1), (3, 2)]
+    );
+    assert_eq!(
+        inventory.outliers(),
+        [ElfSummary {
+            index: 3,
+            items: 3,
+            total: 24000
+        }]
+    );
+
+    let histogram = inventory.histogram(4);
+    assert_eq!(histogram.len(), 4);
+    assert_eq!(histogram.iter().map(|&(_, count)| count).sum::<usize>(), 5);
+    assert_eq!(histogram[0], (4000, 2));
+
+    let empty = Inventory::new(&[]);
+    assert_eq!((empty.median(), empty.histogram(4).len()), (None, 0));
+}
+
+#[test]
+fn calories_are_validated_and_summed_without_overflow() {
+    let error = |input| Day1::parse(input).err().map(|e| (e.line, e.column));
+    assert_eq!(error("1000\n\n\n2000"), Some((3, 1)));
+    assert_eq!(error("1000\n20x0\n"), Some((2, 3)));
+    assert_eq!(error("1000\n4294967296"), Some((2, 1)));
+    assert_eq!(error(""), Some((1, 1)));
+    assert_eq!(Day1::parse("1000\n\n2000"), Day1::parse("1000\n\n2000\n"));
+
+    let elves = Day1::parse("4294967295\n4294967295\n\n4294967295").unwrap();
+    assert_eq!(day1::total(&elves[0]), Some(2 * u64::from(u32::MAX)));
+    assert_eq!(
+        Day1::part2(&elves, &()),
+        Ok(Answer::from(3 * u64::from(u32::MAX)))
+    );
+}
+
+#[test]
+fn snacks_are_rebalanced_close_to_the_lower_bound() {
+    let elves = vec![vec![8, 7], vec![6, 5, 4]];
+
+    let exact = rebalance::rebalance(&elves, 2, Method::Exact).unwrap();
+    assert_eq!(
+        (exact.max_load(), exact.lower_bound(), exact.gap()),
+        (15, 15, 0)
+    );
+
+    let lpt = rebalance::rebalance(&elves, 2, Method::Lpt).unwrap();
+    assert_eq!(lpt.elves(), [vec![8, 5, 4], vec![7, 6]]);
+    assert_eq!(lpt.gap(), 2);
+
+    assert_eq!(Method::for_snacks(5), Method::Exact);
+    assert_eq!("kk".parse(), Ok(Method::KarmarkarKarp));
+    assert!(rebalance::rebalance(&elves, 0, Method::Lpt).is_err());
+}
+
+#[test]
+fn hand_games_are_defined_by_data() {
+    let rps = Game::rock_paper_scissors();
+    let rounds = ["A Y", "B X", "C Z"].map(|round| round.parse::<Round>().unwrap());
+    let total = |game: &Game, reading| {
+        rounds
+            .iter()
+            .map(|round| round.score(game, reading).unwrap())
+            .sum::<u32>()
+    };
+    assert_eq!(total(&rps, Reading::Shape), 15);
+    assert_eq!(total(&rps, Reading::Outcome), 12);
+
+    let doubled = Game::rock_paper_scissors().with_outcome_scores(0, 6, 12);
+    assert_eq!(total(&doubled, Reading::Shape), 24);
+
+    let rpsls = Game::rock_paper_scissors_lizard_spock();
+    let (rock, lizard, spock) = (Shape(0), Shape(3), Shape(4));
+    assert_eq!(rpsls.name(spock), "Spock");
+    assert_eq!(rpsls.outcome(spock, rock), Outcome::Win);
+    assert_eq!(rpsls.outcome(spock, lizard), Outcome::Lose);
+    assert_eq!(rpsls.score(rock, spock), 5 + 6);
+    assert_eq!(rpsls.shape_for(rock, Outcome::Win), Some(spock));
+    assert_eq!(
+        Round::new(rock, Response::X)
+            .unwrap()
+            .my_shape(&rpsls, Reading::Outcome),
+        Some(lizard)
+    );
+    assert_eq!(Round::new(spock, Response::X), None);
+
+    let two = Game::new(&[("a", 1), ("b", 2)], &[(0, 1)]).unwrap();
+    let round = "A Z".parse::<Round>().unwrap();
+    assert_eq!(round.score(&two, Reading::Shape), None);
+    assert_eq!(round.score(&two, Reading::Outcome), None);
+    assert_eq!(
+        "A X"
+            .parse::<Round>()
+            .unwrap()
+            .score(&two, Reading::Outcome),
+        Some(2)
+    );
+
+    assert_eq!(Game::cyclic(&["a", "b"]), Err(GameError::EvenCycle(2)));
+    assert_eq!(
+        Game::new(&[("a", 1), ("b", 2)], &[(0, 1), (1, 0)]),
+        Err(GameError::Mutual(1, 0))
+    );
+    assert_eq!(
+        Game::new(&[("a", 1)], &[(0, 1)]),
+        Err(GameError::UnknownShape(1))
+    );
+}
+
+#[test]
+fn entries_refuse_parts_they_do_not_have() {
+    let entry = registry::find(2022, 10).unwrap();
+    let parsed = entry.parse("noop\n").unwrap();
+
+    for part in [0, 3, u8::MAX] {
+        assert_eq!(
+            entry.solve(&parsed, part),
+            Err(SolutionError::NoSuchPart { day: 10, part })
+        );
+        assert!(entry.run("noop\n", part).is_err());
+    }
+    assert!(entry.solve(&parsed, 2).is_ok());
+}
@@ -0,0 +1,90 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn run_with_stdin(args: &[&str], stdin: &str) -> (bool, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_advent-2022"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+#[test]
+fn stdin_is_read_once_for_both_parts() {
+    let (ok, stdout) = run_with_stdin(&["run", "1", "--input", "-"], "1\n2\n\n3\n");
+    assert!(ok, "{}", stdout);
+    assert!(stdout.contains("Part 1: 3"), "{}", stdout);
+    assert!(stdout.contains("Part 2: 6"), "{}", stdout);
+
+    let (ok, stdout) = run_with_stdin(
+        &["run", "1", "--input", "-", "--format", "csv"],
+        "1\n2\n\n3\n",
+    );
+    assert!(ok, "{}", stdout);
+    assert_eq!(
+        stdout.lines().filter(|line| line.contains(",ok,")).count(),
+        2
+    );
+}
+
+#[test]
+fn inventory_accepts_windows_line_endings() {
+    let (ok, stdout) = run_with_stdin(&["inventory", "--input", "-"], "1\r\n2\r\n\r\n3\r\n");
+    assert!(ok, "{}", stdout);
+    assert!(
+        stdout.starts_with("2 elves carrying 6 calories"),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn verify_reads_inputs_from_the_input_dir_variable() {
+    let output = Command::new(env!("CARGO_BIN_EXE_advent-2022"))
+        .arg("verify")
+        .env("ADVENT_INPUT_DIR", "no/such/dir")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(!output.status.success(), "{}", stdout);
+    assert!(stdout.contains("0 of "), "{}", stdout);
+}
+
+#[test]
+fn streaming_takes_params_but_only_reports_as_text() {
+    let (ok, _) = run_with_stdin(
+        &["run", "1", "--input", "-", "--stream", "--format", "csv"],
+        "1\n2\n\n3\n",
+    );
+    assert!(!ok);
+
+    let (ok, stdout) = run_with_stdin(
+        &[
+            "run",
+            "10",
+            "--input",
+            "-",
+            "--stream",
+            "--param",
+            "day10.cycles=1",
+        ],
+        "addx 3\nnoop\n",
+    );
+    assert!(ok, "{}", stdout);
+    assert!(stdout.contains("Part 1: 1\n"), "{}", stdout);
+}
@@ -0,0 +1,81 @@
+use advent_2022::answer::Answer;
+use advent_2022::solution::Solution;
+use advent_2022::y2022::day11::{Day11, Monkey};
+use advent_2022::{gen, registry};
+
+#[test]
+fn every_generated_input_solves() {
+    for entry in registry::entries() {
+        for seed in 0..4 {
+            let input = gen::generate(entry.year, entry.day, seed, 50)
+                .unwrap_or_else(|| panic!("{} {} has no generator", entry.year, entry));
+
+            for part in entry.parts() {
+                if let Err(e) = entry.run(&input, part) {
+                    panic!(
+                        "{} {} part {} seed {}: {}",
+                        entry.year, entry, part, seed, e
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn generators_are_deterministic() {
+    for entry in registry::entries() {
+        assert_eq!(
+            gen::generate(entry.year, entry.day, 7, 20),
+            gen::generate(entry.year, entry.day, 7, 20),
+            "{} {}",
+            entry.year,
+            entry
+        );
+    }
+}
+
+/// Plays part 1 on the monkeys' notes directly, never reducing a worry level.
+fn exact_monkey_business(monkeys: &[Monkey], rounds: usize) -> u64 {
+    let mut items = monkeys
+        .iter()
+        .map(|monkey| monkey.items().to_vec())
+        .collect::<Vec<_>>();
+    let mut inspected = vec![0u64; monkeys.len()];
+
+    for _ in 0..rounds {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for item in std::mem::take(&mut items[i]) {
+                let item = monkey.operation().apply(item).unwrap() / 3;
+                let (if_true, if_false) = monkey.targets();
+                let to = if item % monkey.divisor() == 0 {
+                    if_true
+                } else {
+                    if_false
+                };
+                items[to].push(item);
+                inspected[i] += 1;
+            }
+        }
+    }
+
+    inspected.sort_unstable();
+    inspected.iter().rev().take(2).product()
+}
+
+#[test]
+fn monkeys_with_relief_match_an_exact_simulation() {
+    let entry = registry::find(2022, 11).unwrap();
+
+    for seed in 0..12 {
+        let input = gen::generate(2022, 11, seed, 3).unwrap();
+        let monkeys = Day11::parse(&input).unwrap();
+
+        assert_eq!(
+            entry.run(&input, 1).unwrap().answer,
+            Answer::from(exact_monkey_business(&monkeys, 20)),
+            "seed {}",
+            seed
+        );
+    }
+}
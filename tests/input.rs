@@ -0,0 +1,33 @@
+use advent_2022::input::{self, Loader, Source};
+use advent_2022::registry;
+use std::path::Path;
+
+#[test]
+fn normalize_strips_carriage_returns_and_trailing_newlines() {
+    assert_eq!(input::normalize("1\r\n2\r\n\r\n3\r\n\r\n"), "1\n2\n\n3");
+    assert_eq!(input::normalize("1\n2\n"), "1\n2");
+    assert_eq!(input::normalize("1\n2"), "1\n2");
+}
+
+#[test]
+fn windows_line_endings_give_the_same_answers() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let loader = Loader::new(root.join("input"));
+
+    for entry in registry::entries() {
+        let sample = input::dir(root, entry.year)
+            .join("samples")
+            .join(format!("day{}.txt", entry.day));
+        let Ok(unix) = loader.load(&Source::File(sample), entry.year, entry.day) else {
+            continue;
+        };
+        let windows = unix.replace('\n', "\r\n");
+
+        for part in entry.parts() {
+            let expected = entry.run(&unix, part).unwrap().answer;
+            let actual = entry.run(&windows, part).unwrap().answer;
+
+            assert_eq!(actual, expected, "{} {} part {}", entry.year, entry, part);
+        }
+    }
+}
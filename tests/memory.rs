@@ -0,0 +1,29 @@
+use advent_2022::memory::{self, Counting, Usage};
+use std::hint::black_box;
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+// One test, so no other test thread allocates while it measures.
+#[test]
+fn measure_counts_allocations_bytes_and_peak() {
+    let (_, usage) = memory::measure(|| black_box(1 + 1));
+    assert_eq!(usage, Usage::default());
+
+    let (_, usage) = memory::measure(|| {
+        for _ in 0..3 {
+            drop(black_box(vec![0u8; 1000]));
+        }
+    });
+    assert_eq!(usage.allocations, 3);
+    assert_eq!(usage.bytes, 3000);
+    assert_eq!(usage.peak, 1000);
+
+    let (held, usage) = memory::measure(|| {
+        let mut held = Vec::<u64>::with_capacity(10);
+        held.reserve_exact(20);
+        held
+    });
+    assert_eq!(usage.allocations, 2);
+    assert_eq!(usage.peak, held.capacity() * 8);
+}
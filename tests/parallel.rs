@@ -0,0 +1,50 @@
+use advent_2022::parallel::{self, Job};
+use advent_2022::{gen, registry};
+
+#[test]
+fn parallel_run_matches_sequential_run_in_order() {
+    let days = registry::entries()
+        .map(|entry| {
+            let input = gen::generate(entry.year, entry.day, 3, 30).unwrap();
+            Job::new(entry, input)
+        })
+        .collect::<Vec<_>>();
+
+    let summary = parallel::run(&days, None, 4);
+
+    let expected = days
+        .iter()
+        .flat_map(|job| {
+            job.entry
+                .parts()
+                .map(move |part| (job.entry, &job.input, part))
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(summary.outcomes.len(), expected.len());
+
+    for (outcome, (entry, input, part)) in summary.outcomes.iter().zip(expected) {
+        assert_eq!((outcome.entry.day, outcome.part), (entry.day, part));
+        assert_eq!(
+            outcome.result.as_ref().unwrap(),
+            &entry.run(input, part).unwrap().answer,
+            "{} {} part {}",
+            entry.year,
+            entry,
+            part
+        );
+    }
+}
+
+#[test]
+fn parse_failures_are_reported_for_each_part() {
+    let entry = registry::find(2022, 1).unwrap();
+    let summary = parallel::run(
+        &[Job::new(entry, "1\nnot a number".to_string())],
+        Some(2),
+        2,
+    );
+
+    assert_eq!(summary.outcomes.len(), 1);
+    assert_eq!(summary.outcomes[0].part, 2);
+    assert!(summary.outcomes[0].result.is_err());
+}
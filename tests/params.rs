@@ -0,0 +1,59 @@
+use advent_2022::answer::Answer;
+use advent_2022::params::{self, Override, ParamError};
+use advent_2022::registry;
+
+const DAY7_SAMPLE: &str = include_str!("../input/2022/samples/day7.txt");
+
+#[test]
+fn overrides_come_from_args_and_files() {
+    assert_eq!(
+        Override::from_arg("day7.disk_size=80_000_000"),
+        Some(Override {
+            day: 7,
+            key: "disk_size".to_string(),
+            value: "80_000_000".to_string(),
+        })
+    );
+    assert_eq!(Override::from_arg("disk_size=1"), None);
+    assert_eq!(Override::from_arg("7.disk_size=1"), None);
+
+    let overrides = params::parse_file(
+        "# what if\n[day7]\ndisk_size = 80_000_000 # bigger\n\n[day10]\ncycles = [20, 60]\n",
+    )
+    .unwrap();
+    assert_eq!(overrides.len(), 2);
+    assert_eq!((overrides[0].day, &*overrides[0].value), (7, "80_000_000"));
+    assert_eq!((overrides[1].day, &*overrides[1].value), (10, "[20, 60]"));
+
+    assert_eq!(
+        params::parse_file("disk_size = 1"),
+        Err(ParamError::Syntax {
+            line: 1,
+            text: "disk_size = 1".to_string()
+        })
+    );
+    assert_eq!(params::list::<u32>("cycles", "20,60"), Ok(vec![20, 60]));
+    assert!(params::positive::<usize>("tail_knots", "0").is_err());
+}
+
+#[test]
+fn overridden_params_change_answers() {
+    let entry = registry::find(2022, 7).unwrap();
+    let mut params = entry.default_params();
+    let run = |params: &dyn params::Params, part| {
+        entry.run_with(DAY7_SAMPLE, part, params).unwrap().answer
+    };
+
+    assert_eq!(run(&*params, 1), Answer::from(95437));
+    assert_eq!(run(&*params, 2), Answer::from(24933642));
+
+    let overrides = [Override::from_arg("day7.small_dir_limit=1000").unwrap()];
+    params::apply(&mut *params, 7, &overrides).unwrap();
+    assert_eq!(run(&*params, 1), Answer::from(584));
+
+    assert_eq!(
+        params.set("disk", "1"),
+        Err(ParamError::UnknownKey("disk".to_string()))
+    );
+    assert!(params.set("disk_size", "big").is_err());
+}
@@ -0,0 +1,64 @@
+use advent_2022::{answers, input, registry};
+use std::path::Path;
+
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn known_answers(year: u16) -> Vec<answers::KnownAnswer> {
+    let path = input::answers_path(root(), year);
+
+    answers::load(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e))
+}
+
+#[test]
+fn known_answers_match() {
+    let mut total = 0;
+    let mut failures = Vec::new();
+
+    for year in registry::years() {
+        let known = known_answers(year);
+        total += known.len();
+
+        failures.extend(
+            answers::verify(year, &known, &input::dir(root(), year))
+                .iter()
+                .map(|(known, mismatch)| {
+                    format!(
+                        "{} day {} part {} ({}): expected {}, {}",
+                        year, known.day, known.part, known.file, known.answer, mismatch
+                    )
+                }),
+        );
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} of {} known answers did not match:\n{}",
+        failures.len(),
+        total,
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn every_solution_has_a_known_answer() {
+    let known = registry::years()
+        .map(|year| (year, known_answers(year)))
+        .collect::<Vec<_>>();
+
+    let missing = registry::entries()
+        .flat_map(|entry| entry.parts().map(move |part| (entry.year, entry.day, part)))
+        .filter(|&(year, day, part)| {
+            !known.iter().any(|(y, answers)| {
+                *y == year
+                    && answers
+                        .iter()
+                        .any(|a| a.day == day && a.part == part && !a.file.starts_with("samples/"))
+            })
+        })
+        .map(|(year, day, part)| format!("{} day {} part {}", year, day, part))
+        .collect::<Vec<_>>();
+
+    assert!(missing.is_empty(), "no answer recorded for {:?}", missing);
+}
@@ -0,0 +1,94 @@
+use advent_2022::error::{SolutionError, StreamError};
+use advent_2022::params::{self, Override};
+use advent_2022::{gen, registry, stream};
+use std::io::Cursor;
+
+#[test]
+fn streaming_gives_the_same_answers() {
+    for entry in registry::entries().filter(|entry| entry.can_stream()) {
+        for seed in 0..3 {
+            let input = gen::generate(entry.year, entry.day, seed, 60).unwrap();
+
+            for text in [input.clone(), input.replace('\n', "\r\n")] {
+                let answers = entry.stream(&mut Cursor::new(text)).unwrap().unwrap();
+
+                for part in entry.parts() {
+                    let expected = entry.run(&input, part).unwrap().answer;
+                    assert_eq!(
+                        answers[usize::from(part) - 1],
+                        expected,
+                        "{} {} part {} seed {}",
+                        entry.year,
+                        entry,
+                        part,
+                        seed
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn streaming_honours_overridden_params() {
+    for (day, arg) in [(9, "day9.tail_knots=3"), (10, "day10.cycles=5,7")] {
+        let entry = registry::find(2022, day).unwrap();
+        let input = gen::generate(2022, day, 1, 60).unwrap();
+        let mut params = entry.default_params();
+        params::apply(&mut *params, day, &[Override::from_arg(arg).unwrap()]).unwrap();
+
+        let answers = entry
+            .stream_with(&mut Cursor::new(&input), &*params)
+            .unwrap()
+            .unwrap();
+        for part in entry.parts() {
+            let expected = entry.run_with(&input, part, &*params).unwrap().answer;
+            assert_eq!(answers[usize::from(part) - 1], expected, "day {}", day);
+        }
+    }
+}
+
+#[test]
+fn streaming_parse_errors_are_located_like_parsing() {
+    for (day, input) in [
+        (1, "1\n\n\n2\n"),
+        (1, "1\n99999999999\n"),
+        (2, "A X\nB Y\nC W\n"),
+        (4, "1-2,3-4\n5-6,7x8\n"),
+    ] {
+        let entry = registry::find(2022, day).unwrap();
+        let streamed = entry.stream(&mut Cursor::new(input)).unwrap();
+        let parsed = entry.parse(input).err().unwrap();
+
+        match streamed {
+            Err(StreamError::Solution(SolutionError::Parse(e))) => {
+                assert_eq!(
+                    (e.line, e.column),
+                    (parsed.line, parsed.column),
+                    "day {}",
+                    day
+                )
+            }
+            other => panic!("day {}: expected a parse error, got {:?}", day, other),
+        }
+    }
+}
+
+#[test]
+fn for_each_line_drops_line_endings_and_trailing_blanks() {
+    let mut lines = Vec::new();
+    stream::for_each_line(Cursor::new("a\r\n\nb\n\n\n"), |index, line| {
+        lines.push((index, line.to_string()));
+        Ok::<_, std::io::Error>(())
+    })
+    .unwrap();
+
+    assert_eq!(
+        lines,
+        [
+            (0, "a".to_string()),
+            (1, String::new()),
+            (2, "b".to_string())
+        ]
+    );
+}
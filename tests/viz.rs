@@ -0,0 +1,30 @@
+use advent_2022::viz::{Backend, Gif, Svg};
+use advent_2022::{input, registry};
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn every_visualization_renders_its_sample() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    for entry in registry::entries().filter(|entry| entry.can_visualize()) {
+        let path = input::dir(root, entry.year)
+            .join("samples")
+            .join(format!("day{}.txt", entry.day));
+        let input = fs::read_to_string(&path).unwrap();
+
+        let frames = entry.visualize(&input).unwrap().unwrap();
+        assert!(
+            !frames.is_empty(),
+            "{} {} drew no frames",
+            entry.year,
+            entry
+        );
+
+        for backend in [&Svg::default() as &dyn Backend, &Gif::default()] {
+            let mut out = Vec::new();
+            backend.render(&frames, &mut out).unwrap();
+            assert!(!out.is_empty(), "{} {} rendered nothing", entry.year, entry);
+        }
+    }
+}